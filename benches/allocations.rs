@@ -0,0 +1,30 @@
+//! Counts the heap allocations made while starting a chain, which the
+//! timeline should keep low. Run with `cargo bench --bench allocations`.
+//! `tests/no_alloc.rs` checks that starting short chains doesn't allocate.
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::allocations;
+use cosmic_time::{chain, id, offset, Duration, Instant, Timeline};
+
+fn main() {
+    let id = id::Offset::unique();
+    let mut timeline = Timeline::new();
+    let millis = Duration::from_millis;
+    let chain = || {
+        chain!(
+            id,
+            offset(Duration::ZERO),
+            offset(millis(100)).x(20.0).y(10.0),
+            offset(millis(100)).x(20.0).y(40.0),
+            offset(millis(100)),
+        )
+    };
+    // Warm up, so the timeline's maps already have room for the chain.
+    timeline.set_chain(chain()).start_at(Instant::now());
+
+    let building = allocations(|| drop(chain()));
+    let starting = allocations(|| timeline.set_chain(chain()).start_at(Instant::now()));
+    println!("4 keyframe offset chain, allocations to build: {building}");
+    println!("4 keyframe offset chain, allocations to set and start: {starting}");
+}
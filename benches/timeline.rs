@@ -0,0 +1,81 @@
+use cosmic_time::{chain, id, toggler, Duration, Instant, Timeline};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// A toggler chain bouncing between off and on, `links` keyframes long.
+fn bouncing(id: id::Toggler, links: usize) -> chain::Toggler {
+    (0..links).fold(chain::Toggler::new(id), |chain, i| {
+        chain.link(toggler(Duration::from_millis(100)).percent((i % 2) as f32))
+    })
+}
+
+fn get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get");
+    for links in [2, 16, 256, 4096] {
+        let id = id::Toggler::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(bouncing(id.clone(), links))
+            .start_at(now);
+        // Halfway through, so `get` has to search past half the keyframes.
+        timeline.now(now + Duration::from_millis(50 * links as u64));
+        let id = id.into();
+        let _ = group.bench_with_input(BenchmarkId::from_parameter(links), &id, |b, id| {
+            b.iter(|| timeline.get(black_box(id), 0));
+        });
+    }
+    group.finish();
+}
+
+fn get_many(c: &mut Criterion) {
+    // Every frame looks up each animating widget, so the cost of hashing
+    // the Ids adds up with many tracks.
+    let ids: Vec<_> = (0..500).map(|_| id::Toggler::unique()).collect();
+    let mut timeline = Timeline::new();
+    let now = Instant::now();
+    timeline.set_and_start(ids.iter().map(|id| bouncing(id.clone(), 4)));
+    timeline.now(now + Duration::from_millis(50));
+    let ids: Vec<_> = ids.into_iter().map(Into::into).collect();
+    let _ = c.bench_function("get 500 tracks", |b| {
+        b.iter(|| {
+            for id in &ids {
+                let _ = black_box(timeline.get(black_box(id), 0));
+            }
+        });
+    });
+}
+
+fn start_at(c: &mut Criterion) {
+    let mut group = c.benchmark_group("start_at");
+    for chains in [1, 64, 1024] {
+        let ids: Vec<_> = (0..chains).map(|_| id::Toggler::unique()).collect();
+        let _ = group.bench_with_input(BenchmarkId::from_parameter(chains), &ids, |b, ids| {
+            b.iter_batched_ref(
+                || {
+                    let mut timeline = Timeline::new();
+                    let _ = ids.iter().fold(&mut timeline, |timeline, id| {
+                        timeline.set_chain(bouncing(id.clone(), 4))
+                    });
+                    timeline
+                },
+                |timeline| timeline.start_at(Instant::now()),
+                criterion::BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+fn as_subscription(c: &mut Criterion) {
+    let mut group = c.benchmark_group("as_subscription");
+    let idle = Timeline::new();
+    let _ = group.bench_function("idle", |b| b.iter(|| black_box(&idle).as_subscription()));
+
+    let mut busy = Timeline::new();
+    busy.set_and_start((0..64).map(|_| bouncing(id::Toggler::unique(), 4).loop_forever()));
+    let _ = group.bench_function("busy", |b| b.iter(|| black_box(&busy).as_subscription()));
+    group.finish();
+}
+
+criterion_group!(benches, get, get_many, start_at, as_subscription);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Length, Subscription};
+use cosmic::widget::{button, column, text};
+use cosmic::Element;
+
+use cosmic_time::{anim, chain, id, Duration, Instant, Quadratic, Timeline};
+
+id!(DETAILS: Accordion);
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Accordion>(Settings::default(), ())
+}
+
+struct Accordion {
+    core: Core,
+    timeline: Timeline,
+    expanded: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Toggle,
+    Tick(Instant),
+}
+
+impl cosmic::Application for Accordion {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimeAccordion";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        (
+            Self {
+                core,
+                timeline: Timeline::new(),
+                expanded: false,
+            },
+            Task::none(),
+        )
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Toggle => {
+                self.expanded = !self.expanded;
+                let duration = Duration::from_millis(300);
+                let ease = Quadratic::InOut;
+                let animation = if self.expanded {
+                    chain::Accordion::on(DETAILS.clone(), duration, ease)
+                } else {
+                    chain::Accordion::off(DETAILS.clone(), duration, ease)
+                };
+                self.timeline.set_chain(animation).start();
+            }
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        let details = column()
+            .push(text("Cosmic Time animates widgets from a timeline."))
+            .push(text("Chains of keyframes are started in update,"))
+            .push(text("and drawn in view with the anim! macro."))
+            .spacing(10)
+            .padding(10);
+
+        column()
+            .push(
+                button::standard(if self.expanded {
+                    "Hide details"
+                } else {
+                    "Show details"
+                })
+                .on_press(Message::Toggle),
+            )
+            .push(anim!(DETAILS, &self.timeline, self.expanded, details))
+            .push(text("Everything below moves with the accordion."))
+            .width(Length::Fill)
+            .padding(20)
+            .spacing(10)
+            .into()
+    }
+}
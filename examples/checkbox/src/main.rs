@@ -0,0 +1,101 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Alignment, Subscription};
+use cosmic::widget::column;
+use cosmic::Element;
+
+use cosmic_time::{anim, chain, id, Instant, Timeline};
+
+id!(QUICK: Checkbox, SLOW: Checkbox);
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Checkboxes>(Settings::default(), ())
+}
+
+struct Checkboxes {
+    core: Core,
+    timeline: Timeline,
+    quick: bool,
+    slow: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Quick(chain::Checkbox, bool),
+    Slow(chain::Checkbox, bool),
+    Tick(Instant),
+}
+
+impl cosmic::Application for Checkboxes {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimeCheckbox";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        (
+            Self {
+                core,
+                timeline: Timeline::new(),
+                quick: false,
+                slow: true,
+            },
+            Task::none(),
+        )
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Quick(chain, checked) => {
+                self.quick = checked;
+                self.timeline.set_chain(chain).start();
+            }
+            Message::Slow(chain, checked) => {
+                self.slow = checked;
+                self.timeline.set_chain(chain).start();
+            }
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        column()
+            .push(anim!(
+                QUICK,
+                &self.timeline,
+                String::from("Checked in 100ms"),
+                self.quick,
+                Message::Quick
+            ))
+            // Draws the checkmark in over 500ms, five times the default.
+            .push(
+                anim!(
+                    SLOW,
+                    &self.timeline,
+                    String::from("Checked in 500ms"),
+                    self.slow,
+                    Message::Slow
+                )
+                .anim_multiplier(5.0),
+            )
+            .padding(20)
+            .spacing(20)
+            .align_x(Alignment::Center)
+            .into()
+    }
+}
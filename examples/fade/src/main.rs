@@ -0,0 +1,106 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Alignment, Length, Subscription};
+use cosmic::widget::{button, column, container, text};
+use cosmic::Element;
+
+use cosmic_time::{anim, chain, id, Duration, Instant, Linear, Timeline};
+
+id!(CARD: Opacity);
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Fade>(Settings::default(), ())
+}
+
+struct Fade {
+    core: Core,
+    timeline: Timeline,
+    is_shown: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Toggle,
+    Tick(Instant),
+}
+
+impl cosmic::Application for Fade {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimeFade";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        use cosmic_time::opacity;
+        let mut timeline = Timeline::new();
+        // Fade the card in from fully transparent when the app opens.
+        timeline
+            .set_chain(chain![
+                CARD,
+                opacity(Duration::ZERO).value(0.),
+                opacity(Duration::from_secs(1)).value(1.),
+            ])
+            .start();
+
+        (
+            Self {
+                core,
+                timeline,
+                is_shown: true,
+            },
+            Task::none(),
+        )
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Toggle => {
+                // `fade_in` and `fade_out` continue from the current opacity,
+                // so toggling halfway through a fade doesn't jump.
+                let duration = Duration::from_millis(500);
+                let chain = if self.is_shown {
+                    chain::Opacity::fade_out(CARD.clone(), duration, Linear::InOut)
+                } else {
+                    chain::Opacity::fade_in(CARD.clone(), duration, Linear::InOut)
+                };
+                self.is_shown = !self.is_shown;
+                self.timeline.set_chain(chain).start();
+            }
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        // `FadeText` only fades the text color its content inherits, because
+        // renderers can't draw a whole layer with an alpha. A background or
+        // border in here would stay opaque; widgets with their own colors
+        // can read `CARD.opacity(&self.timeline)` to fade those too.
+        column()
+            .push(anim!(
+                CARD,
+                &self.timeline,
+                container(text("Hello from fading text!").size(32))
+                    .padding(20)
+                    .center(Length::Fill),
+            ))
+            .push(button::standard("Toggle").on_press(Message::Toggle))
+            .padding(20)
+            .align_x(Alignment::Center)
+            .into()
+    }
+}
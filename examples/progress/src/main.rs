@@ -0,0 +1,87 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Alignment, Subscription};
+use cosmic::widget::{button, column};
+use cosmic::Element;
+
+use cosmic_time::{anim, chain, id, Duration, Instant, Quadratic, Timeline};
+
+id!(BAR: Progress);
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Progress>(Settings::default(), ())
+}
+
+struct Progress {
+    core: Core,
+    timeline: Timeline,
+    value: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Bump,
+    Tick(Instant),
+}
+
+impl cosmic::Application for Progress {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimeProgress";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        (
+            Self {
+                core,
+                timeline: Timeline::new(),
+                value: 0.,
+            },
+            Task::none(),
+        )
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Bump => {
+                self.value = (self.value + 10.).min(100.);
+                // `to` starts with a lazy keyframe, so pressing again before the
+                // last ease is done continues from wherever the bar is now.
+                self.timeline
+                    .set_chain(chain::Progress::to(
+                        BAR.clone(),
+                        self.value,
+                        Duration::from_millis(300),
+                        Quadratic::Out,
+                    ))
+                    .start();
+            }
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        column()
+            .push(anim!(BAR, &self.timeline, 0.0..=100.0))
+            .push(button::standard("+10%").on_press(Message::Bump))
+            .padding(20)
+            .spacing(20)
+            .align_x(Alignment::Center)
+            .into()
+    }
+}
@@ -0,0 +1,87 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Length, Subscription};
+use cosmic::widget::{container, text};
+use cosmic::Element;
+
+use cosmic_time::{anim, chain, id, Duration, Instant, Timeline};
+
+id!(PANEL: Background);
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Pulse>(Settings::default(), ())
+}
+
+struct Pulse {
+    core: Core,
+    timeline: Timeline,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Tick(Instant),
+}
+
+impl cosmic::Application for Pulse {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimePulse";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        use cosmic_time::{background, Sinusoidal};
+        let mut timeline = Timeline::new();
+        // Any two colors work, no theme variants needed. `loop_smooth` adds
+        // the way back to the first color, so the pulse never jumps.
+        timeline
+            .set_chain(
+                chain![
+                    PANEL,
+                    background(Duration::ZERO).color([0.58, 0.2, 0.92, 1.]),
+                    background(Duration::from_millis(1200))
+                        .color([0.13, 0.76, 0.86, 1.])
+                        .ease(Sinusoidal::InOut),
+                ]
+                .loop_smooth(),
+            )
+            .start();
+
+        (Self { core, timeline }, Task::none())
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        container(
+            anim!(
+                PANEL,
+                &self.timeline,
+                container(text("Pulsing").size(32))
+                    .center_x(300)
+                    .center_y(200),
+            )
+            .radius(12.),
+        )
+        .center(Length::Fill)
+        .into()
+    }
+}
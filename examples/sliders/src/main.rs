@@ -0,0 +1,125 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Alignment, Subscription};
+use cosmic::widget::{button, column};
+use cosmic::Element;
+
+use cosmic_time::{anim, chain, id, slider, Duration, Instant, Quadratic, Timeline};
+
+id!(LEFT: Slider, RIGHT: Slider);
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Sliders>(Settings::default(), ())
+}
+
+struct Sliders {
+    core: Core,
+    timeline: Timeline,
+    value: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Left(f32),
+    Right(f32),
+    Reset,
+    Tick(Instant),
+}
+
+impl Sliders {
+    // Eases a slider from wherever its handle is shown now, which is
+    // `self.value` unless it is still easing.
+    fn ease(&self, id: &id::Slider, value: f32, duration: Duration) -> chain::Slider {
+        let from = id.value(&self.timeline).unwrap_or(self.value);
+        chain![
+            id,
+            slider(Duration::ZERO).value(from),
+            slider(duration).value(value).ease(Quadratic::InOut),
+        ]
+    }
+
+    // The dragged slider follows the mouse, and the other eases after it.
+    fn sync(&mut self, dragged: &id::Slider, other: &id::Slider, value: f32) {
+        let chain = self.ease(other, value, Duration::from_millis(200));
+        self.value = value;
+        // The dragged slider may still be easing, after a reset or after
+        // following the other slider. The animation would keep overriding its
+        // handle, so the drag clears it and the handle follows the mouse.
+        self.timeline
+            .clear_chain(dragged.clone())
+            .set_chain(chain)
+            .start();
+    }
+}
+
+impl cosmic::Application for Sliders {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimeSliders";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        (
+            Self {
+                core,
+                timeline: Timeline::new(),
+                value: 50.,
+            },
+            Task::none(),
+        )
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Left(value) => self.sync(&LEFT, &RIGHT, value),
+            Message::Right(value) => self.sync(&RIGHT, &LEFT, value),
+            Message::Reset => {
+                // Slow enough to grab either handle before it settles.
+                let duration = Duration::from_secs(2);
+                let left = self.ease(&LEFT, 50., duration);
+                let right = self.ease(&RIGHT, 50., duration);
+                self.value = 50.;
+                self.timeline.set_chain(left).set_chain(right).start();
+            }
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        column()
+            .push(anim!(
+                LEFT,
+                &self.timeline,
+                0.0..=100.0,
+                self.value,
+                Message::Left
+            ))
+            .push(anim!(
+                RIGHT,
+                &self.timeline,
+                0.0..=100.0,
+                self.value,
+                Message::Right
+            ))
+            .push(button::standard("Reset").on_press(Message::Reset))
+            .padding(20)
+            .spacing(20)
+            .align_x(Alignment::Center)
+            .into()
+    }
+}
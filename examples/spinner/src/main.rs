@@ -0,0 +1,94 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Length, Subscription};
+use cosmic::widget::container;
+use cosmic::Element;
+
+use cosmic_time::{anim, chain, id, reexports::iced_core::svg, Duration, Instant, Timeline};
+
+id!(SPINNER: Rotate);
+
+const ICON: &[u8] = br##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16">
+  <path d="M8 1a7 7 0 1 0 7 7h-2a5 5 0 1 1-5-5z" fill="#3584e4"/>
+</svg>"##;
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Spinner>(Settings::default(), ())
+}
+
+struct Spinner {
+    core: Core,
+    timeline: Timeline,
+    icon: svg::Handle,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Tick(Instant),
+}
+
+impl cosmic::Application for Spinner {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimeSpinner";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        use cosmic_time::rotate;
+        let mut timeline = Timeline::new();
+        // A full turn every second. `360` and `0` look the same, so looping
+        // straight back to the first keyframe has no visible jump.
+        timeline
+            .set_chain(
+                chain![
+                    SPINNER,
+                    rotate(Duration::ZERO).degrees(0.),
+                    rotate(Duration::from_secs(1)).degrees(360.),
+                ]
+                .loop_forever(),
+            )
+            .start();
+        // `chain::Rotate::spin(SPINNER.clone(), Duration::from_secs(1))` is
+        // a shorthand for the same chain.
+
+        (
+            Self {
+                core,
+                timeline,
+                icon: svg::Handle::from_memory(ICON),
+            },
+            Task::none(),
+        )
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        container(
+            anim!(SPINNER, &self.timeline, self.icon.clone())
+                .width(64)
+                .height(64),
+        )
+        .center(Length::Fill)
+        .into()
+    }
+}
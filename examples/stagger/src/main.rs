@@ -0,0 +1,113 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Alignment, Length, Subscription};
+use cosmic::widget::{button, column, container, text};
+use cosmic::Element;
+
+use cosmic_time::{anim, chain, id, Duration, Instant, Timeline};
+
+id!(
+    FIRST: Opacity,
+    SECOND: Opacity,
+    THIRD: Opacity,
+    FOURTH: Opacity,
+    FIFTH: Opacity,
+);
+
+// The items, in the order they are revealed.
+fn items() -> [&'static id::Opacity; 5] {
+    [&FIRST, &SECOND, &THIRD, &FOURTH, &FIFTH]
+}
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Stagger>(Settings::default(), ())
+}
+
+struct Stagger {
+    core: Core,
+    timeline: Timeline,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Reveal,
+    Tick(Instant),
+}
+
+impl Stagger {
+    // Fades the items in one after another, 100ms apart.
+    fn reveal(&mut self) {
+        use cosmic_time::opacity;
+        let chains = items().into_iter().map(|id| {
+            chain![
+                id,
+                opacity(Duration::ZERO).value(0.),
+                opacity(Duration::from_millis(400)).value(1.),
+            ]
+        });
+        self.timeline
+            .set_chains_staggered(chains, Duration::from_millis(100));
+    }
+}
+
+impl cosmic::Application for Stagger {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimeStagger";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        let mut stagger = Self {
+            core,
+            timeline: Timeline::new(),
+        };
+        stagger.reveal();
+        (stagger, Task::none())
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Reveal => self.reveal(),
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        let items: Vec<Element<Message>> = items()
+            .into_iter()
+            .enumerate()
+            .map(|(i, id)| {
+                anim!(
+                    id,
+                    &self.timeline,
+                    container(text(format!("Item {}", i + 1)).size(24))
+                        .padding(10)
+                        .width(Length::Fill),
+                )
+                .into()
+            })
+            .collect();
+        column()
+            .push(column::with_children(items).spacing(10))
+            .push(button::standard("Reveal again").on_press(Message::Reveal))
+            .padding(20)
+            .spacing(20)
+            .align_x(Alignment::Center)
+            .into()
+    }
+}
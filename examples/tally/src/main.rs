@@ -0,0 +1,88 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Alignment, Subscription};
+use cosmic::widget::{button, column, text};
+use cosmic::Element;
+
+use cosmic_time::{chain, id, Duration, Exponential, Instant, Timeline};
+
+id!(SCORE: Value);
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Tally>(Settings::default(), ())
+}
+
+struct Tally {
+    core: Core,
+    timeline: Timeline,
+    score: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Score,
+    Tick(Instant),
+}
+
+impl cosmic::Application for Tally {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimeTally";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        (
+            Self {
+                core,
+                timeline: Timeline::new(),
+                score: 0,
+            },
+            Task::none(),
+        )
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Score => {
+                self.score += 250;
+                // The animated number isn't tied to any widget, it is only
+                // read back in `view`, rounded, to count up like a scoreboard.
+                self.timeline
+                    .set_chain(chain::Value::to(
+                        SCORE.clone(),
+                        self.score as f32,
+                        Duration::from_millis(800),
+                        Exponential::Out,
+                    ))
+                    .start();
+            }
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        let shown = self.timeline.value(&SCORE.clone().into()).unwrap_or(0.);
+        column()
+            .push(text(format!("{:.0}", shown)).size(48))
+            .push(button::standard("Score!").on_press(Message::Score))
+            .padding(20)
+            .spacing(20)
+            .align_x(Alignment::Center)
+            .into()
+    }
+}
@@ -0,0 +1,77 @@
+use cosmic::app::{Core, Settings, Task};
+use cosmic::iced::{Length, Subscription};
+use cosmic::widget::container;
+use cosmic::Element;
+
+use cosmic_time::{anim, chain, id, Duration, Instant, Timeline};
+
+id!(TITLE: Text);
+
+pub fn main() -> cosmic::iced::Result {
+    cosmic::app::run::<Title>(Settings::default(), ())
+}
+
+struct Title {
+    core: Core,
+    timeline: Timeline,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Tick(Instant),
+}
+
+impl cosmic::Application for Title {
+    type Executor = cosmic::executor::Default;
+    type Flags = ();
+    type Message = Message;
+
+    const APP_ID: &'static str = "com.system76.CosmicTimeTitle";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(core: Core, _flags: ()) -> (Self, Task<Message>) {
+        use cosmic_time::{text, Quadratic};
+        let mut timeline = Timeline::new();
+        // The title starts small and invisible, then grows while fading in.
+        // Colors are red, green, blue and alpha, and blend in linear space
+        // so the fade doesn't dip darker halfway through.
+        timeline
+            .set_chain(chain![
+                TITLE,
+                text(Duration::ZERO).size(16.).color([0.2, 0.4, 0.9, 0.]),
+                text(Duration::from_millis(800))
+                    .size(64.)
+                    .color([0.2, 0.4, 0.9, 1.])
+                    .ease(Quadratic::Out),
+            ])
+            .start();
+
+        (Self { core, timeline }, Task::none())
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        self.timeline
+            .as_subscription()
+            .map(|(_, now)| Message::Tick(now))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Tick(now) => self.timeline.now(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        container(anim!(TITLE, &self.timeline, "Cosmic Time"))
+            .center(Length::Fill)
+            .into()
+    }
+}
@@ -1,13 +1,285 @@
+// The `Id` and `Chain` that every keyframe type needs, for keyframes of
+// type `$keyframe`. `$a` names the animation in docs, like "an opacity".
+// Type specific methods go in their own `impl` blocks.
+macro_rules! keyframe_chain {
+  ($keyframe:ident, $a:literal) => {
+    #[doc = concat!("The Id of ", $a, " animation. Used for linking animation built in `update()` with widget output in `view()`")]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct Id($crate::reexports::iced_core::widget::Id);
+
+    impl Id {
+        /// Creates a custom [`Id`].
+        pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+            Self($crate::reexports::iced_core::widget::Id::new(id))
+        }
+
+        /// Creates a unique [`Id`].
+        ///
+        /// This function produces a different [`Id`] every time it is called.
+        #[must_use]
+        pub fn unique() -> Self {
+            Self($crate::reexports::iced_core::widget::Id::unique())
+        }
+
+        /// Used by [`crate::chain!`] macro
+        #[must_use]
+        pub fn into_chain(self) -> Chain {
+            Chain::new(self)
+        }
+
+        /// Used by [`crate::chain!`] macro
+        #[must_use]
+        pub fn into_chain_with_children(self, children: Vec<$keyframe>) -> Chain {
+            Chain::with_children(self, children)
+        }
+    }
+
+    impl From<Id> for $crate::reexports::iced_core::widget::Id {
+        fn from(id: Id) -> Self {
+            id.0
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    /// An animation, where each keyframe is "chained" together.
+    pub struct Chain {
+        id: Id,
+        links: Vec<$keyframe>,
+        repeat: $crate::keyframes::Repeat,
+        delay: $crate::Duration,
+    }
+
+    impl Chain {
+        #[doc = concat!("Create a new chain for ", $a, " animation.")]
+        /// You probably don't want to use this directly, and should
+        /// use the [`crate::chain!`] macro.
+        #[must_use]
+        pub fn new(id: Id) -> Self {
+            Self::with_children(id, Vec::new())
+        }
+
+        /// Create a chain pre-filled with children.
+        /// You probably don't want to use this directly, and should
+        /// use the [`crate::chain!`] macro.
+        #[must_use]
+        pub fn with_children(id: Id, children: Vec<$keyframe>) -> Self {
+            Chain {
+                id,
+                links: children,
+                repeat: $crate::keyframes::Repeat::Never,
+                delay: $crate::Duration::ZERO,
+            }
+        }
+
+        /// Link another keyframe, (very similar to push)
+        /// You probably don't want to use this directly, and should
+        /// use the [`crate::chain!`] macro.
+        #[must_use]
+        pub fn link(mut self, keyframe: $keyframe) -> Self {
+            self.links.push(keyframe);
+            self
+        }
+
+        /// Sets the animation to loop forever.
+        #[must_use]
+        pub fn loop_forever(mut self) -> Self {
+            self.repeat = $crate::keyframes::Repeat::Forever;
+            self
+        }
+
+        /// Sets the animation to play `times` times, then hold its last keyframe.
+        #[must_use]
+        pub fn loop_n(mut self, times: u32) -> Self {
+            self.repeat = $crate::keyframes::Repeat::Times(times);
+            self
+        }
+
+        /// Sets the animation to play forward, then backward, forever.
+        #[must_use]
+        pub fn ping_pong(mut self) -> Self {
+            self.repeat = $crate::keyframes::Repeat::PingPong;
+            self
+        }
+
+        /// Sets the animation to only loop once.
+        /// This is the default, and only useful to
+        /// stop an animation that was previously set
+        /// to loop forever.
+        #[must_use]
+        pub fn loop_once(mut self) -> Self {
+            self.repeat = $crate::keyframes::Repeat::Never;
+            self
+        }
+
+        /// Waits `delay` after the animation is started before playing it,
+        /// showing the first keyframe meanwhile.
+        #[must_use]
+        pub fn delay(mut self, delay: $crate::Duration) -> Self {
+            self.delay = delay;
+            self
+        }
+
+        /// Sets the animation to loop forever, with a closing keyframe back to
+        /// the first keyframe's value so the loop has no jump. The closing
+        /// keyframe takes as long as the first link, call this after linking
+        /// all keyframes.
+        #[must_use]
+        pub fn loop_smooth(mut self) -> Self {
+            if let [first, second, ..] = self.links[..] {
+                self.links.push($keyframe {
+                    at: second.at,
+                    ..first
+                });
+            }
+            self.repeat = $crate::keyframes::Repeat::Forever;
+            self
+        }
+
+        /// Reverses the order of the keyframes, keeping the time between each
+        /// pair of them, so the animation plays backward. Eases stay with the
+        /// stretch of time they cover, so only symmetric eases like `InOut`
+        /// mirror exactly. Call this after linking all keyframes.
+        #[must_use]
+        pub fn reversed(mut self) -> Self {
+            let mut timing: Vec<_> = self.links.iter().map(|link| (link.at, link.ease)).collect();
+            if let Some(between) = timing.get_mut(1..) {
+                between.reverse();
+            }
+            self.links.reverse();
+            for (link, (at, ease)) in self.links.iter_mut().zip(timing) {
+                link.at = at;
+                link.ease = ease;
+            }
+            self
+        }
+    }
+
+    impl From<Chain> for $crate::timeline::Chain {
+        fn from(chain: Chain) -> Self {
+            $crate::timeline::Chain::new(
+                chain.id.into(),
+                chain.repeat,
+                chain
+                    .links
+                    .into_iter()
+                    .map(std::convert::Into::into)
+                    .collect::<Vec<_>>(),
+            )
+            .delay(chain.delay)
+        }
+    }
+  };
+}
+
+// A keyframe type animating a single number, `$field`. New keyframes are at
+// `$default`, and lazy keyframes start from `$lazy` when there is no current
+// value.
+macro_rules! keyframe {
+  (
+    $(#[$meta:meta])*
+    $name:ident, $noun:literal,
+    $(#[$field_meta:meta])*
+    $field:ident: $default:literal, lazy $lazy:literal $(,)?
+  ) => {
+    $(#[$meta])*
+    #[must_use = "Keyframes are intended to be used in an animation chain."]
+    #[derive(Debug, Clone, Copy)]
+    pub struct $name {
+        at: $crate::MovementType,
+        ease: $crate::Ease,
+        $field: f32,
+        is_eager: bool,
+    }
+
+    impl $name {
+        #[doc = concat!("Create a new ", $noun, " keyframe, at `", stringify!($default), "`.")]
+        pub fn new(at: impl Into<$crate::MovementType>) -> $name {
+            $name {
+                at: at.into(),
+                ease: $crate::Linear::InOut.into(),
+                $field: $default,
+                is_eager: true,
+            }
+        }
+
+        #[doc = concat!("Create a lazy ", $noun, " keyframe, that continues from the current value.")]
+        #[doc = concat!("Without one, it starts from its own value, `", stringify!($lazy), "` unless set.")]
+        pub fn lazy(at: impl Into<$crate::MovementType>) -> $name {
+            $name {
+                at: at.into(),
+                ease: $crate::Linear::InOut.into(),
+                $field: $lazy,
+                is_eager: false,
+            }
+        }
+
+        $(#[$field_meta])*
+        pub fn $field(mut self, $field: f32) -> Self {
+            self.$field = $field;
+            self
+        }
+
+        /// The ease used to animate into this keyframe.
+        pub fn ease<E: Into<$crate::Ease>>(mut self, ease: E) -> Self {
+            self.ease = ease.into();
+            self
+        }
+    }
+
+    #[rustfmt::skip]
+    impl From<$name> for Vec<Option<$crate::timeline::Frame>> {
+        fn from(keyframe: $name) -> Vec<Option<$crate::timeline::Frame>> {
+          if keyframe.is_eager {
+            vec![Some($crate::timeline::Frame::eager(keyframe.at, keyframe.$field, keyframe.ease))]
+          } else {
+            vec![Some($crate::timeline::Frame::lazy(keyframe.at, keyframe.$field, keyframe.ease))]
+          }
+        }
+    }
+  };
+}
+
+mod accordion;
+mod background;
 mod cards;
+mod checkbox;
+mod frame_index;
 mod helpers;
+mod offset;
+mod opacity;
+mod path;
+mod progress;
+mod rotate;
+mod scrollable;
+mod slider;
+mod text;
 mod toggler;
+mod two_state;
+mod value;
 
+pub use accordion::Accordion;
+pub use background::Background;
 pub use cards::Cards;
+pub use checkbox::Checkbox;
+pub use frame_index::FrameIndex;
 pub use helpers::cards;
 pub use helpers::id;
 pub use helpers::lazy;
-pub use helpers::{chain, toggler};
+pub use helpers::{
+    accordion, background, chain, checkbox, frame_index, offset, opacity, path, progress, rotate,
+    scrollable, slider, text, toggler, two_state, value,
+};
+pub use offset::{Edge, Offset};
+pub use opacity::Opacity;
+pub use path::Path;
+pub use progress::Progress;
+pub use rotate::Rotate;
+pub use scrollable::Scrollable;
+pub use slider::Slider;
+pub use text::Text;
 pub use toggler::Toggler;
+pub use two_state::TwoState;
+pub use value::Value;
 /// The macro used to cleanly and efficently build an animation chain.
 /// Works for ann Id's that implement `into_chain` and `into_chain_with_children`
 #[macro_export]
@@ -20,6 +292,15 @@ macro_rules! chain{
   };
 }
 
+/// Sets every chain on the timeline and starts them all exactly once.
+/// The chains may be of different keyframe types.
+#[macro_export]
+macro_rules! chains{
+  ($timeline:expr, $($chain:expr),+ $(,)?) => {
+    $timeline.set_and_start([$($crate::Chain::from($chain)),+])
+  };
+}
+
 /// The macro used to clean up animation's view code.
 #[macro_export]
 macro_rules! anim{
@@ -28,9 +309,43 @@ macro_rules! anim{
   };
 }
 
+/// Declares animation Ids as statics, each made unique on first use.
+/// Takes the names of the statics and the types of [`crate::id`] they hold,
+/// and several can be declared at once.
+///
+/// ```
+/// use cosmic_time::{chain, chains, id, opacity, toggler, Duration, Timeline};
+///
+/// // Rather than `static TOGGLER: Lazy<id::Toggler> = Lazy::new(id::Toggler::unique);`
+/// id!(TOGGLER: Toggler, pub FADE: Opacity);
+///
+/// let mut timeline = Timeline::new();
+/// chains!(
+///     timeline,
+///     chain!(TOGGLER, toggler(Duration::ZERO)),
+///     chain!(FADE, opacity(Duration::ZERO).value(0.5)),
+/// );
+/// assert_eq!(Some(0.5), FADE.opacity(&timeline));
+/// ```
+#[macro_export]
+macro_rules! id{
+  ($($(#[$meta:meta])* $vis:vis $name:ident: $ty:ident),+ $(,)?) => {
+    $(
+      $(#[$meta])*
+      $vis static $name: ::std::sync::LazyLock<$crate::id::$ty> =
+        ::std::sync::LazyLock::new($crate::id::$ty::unique);
+    )+
+  };
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Repeat {
     #[default]
     Never,
     Forever,
+    /// Plays this many times, then holds the last keyframe. 0 plays once.
+    Times(u32),
+    /// Plays forward, then backward, forever.
+    PingPong,
 }
@@ -0,0 +1,96 @@
+use crate::reexports::iced_core::Element;
+
+use crate::{accordion, chain, lazy::accordion as lazy, Duration, Ease};
+
+keyframe_chain!(Accordion, "an accordion");
+
+impl Id {
+    /// How far the accordion is expanded, from `0.0` collapsed to `1.0`
+    /// expanded. `None` if the accordion was never animated.
+    #[must_use]
+    pub fn percent(&self, timeline: &crate::Timeline) -> Option<f32> {
+        timeline
+            .get(&self.clone().into(), 0)
+            .map(|interped| interped.value)
+    }
+
+    /// Used by [`crate::anim!`] macro
+    pub fn as_widget<'a, Message, Renderer>(
+        self,
+        timeline: &crate::Timeline,
+        is_expanded: bool,
+        content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>,
+    ) -> crate::widget::Accordion<'a, Message, Renderer> {
+        let percent = self
+            .percent(timeline)
+            .unwrap_or(if is_expanded { 1.0 } else { 0.0 });
+        crate::widget::Accordion::new(content).percent(percent)
+    }
+}
+
+impl Chain {
+    /// Returns an animation from wherever the accordion currently is to
+    /// fully expanded.
+    #[must_use]
+    pub fn on(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            accordion(duration).percent(1.0).ease(ease),
+        )
+    }
+
+    /// Returns an animation from wherever the accordion currently is to
+    /// fully collapsed.
+    #[must_use]
+    pub fn off(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            accordion(duration).percent(0.0).ease(ease),
+        )
+    }
+}
+
+keyframe!(
+    /// A keyframe for how far an accordion is expanded, from `0.0` collapsed
+    /// to `1.0` expanded. See [`crate::widget::Accordion`].
+    Accordion,
+    "accordion",
+    /// How far the accordion is expanded at this keyframe.
+    percent: 1.0,
+    lazy 0.0,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Linear, Timeline};
+
+    #[test]
+    fn expands_from_collapsed() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+
+        // Keyframes are expanded unless told otherwise.
+        let expanded = Id::unique();
+        timeline
+            .set_chain(chain!(expanded, accordion(Duration::ZERO)))
+            .start_at(now);
+        assert_eq!(Some(1.0), expanded.percent(&timeline));
+
+        // But an accordion that was never animated is collapsed, so
+        // expanding it starts from nothing.
+        let id = Id::unique();
+        timeline
+            .set_chain(Chain::on(
+                id.clone(),
+                Duration::from_millis(100),
+                Linear::InOut,
+            ))
+            .start_at(now);
+        assert_eq!(Some(0.0), id.percent(&timeline));
+        timeline.now(now + Duration::from_millis(25));
+        assert_eq!(Some(0.25), id.percent(&timeline));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::reexports::iced_core::{Color, Element};
+
+use crate::timeline::Frame;
+use crate::{Ease, Linear, MovementType};
+
+keyframe_chain!(Background, "a background");
+
+impl Id {
+    /// The animated background color.
+    #[must_use]
+    pub fn color(&self, timeline: &crate::Timeline) -> Option<Color> {
+        let id = self.clone().into();
+        let mut channels = (0..4).map(|index| timeline.get(&id, index).map(|i| i.value));
+        let [r, g, b, a] = [(); 4].map(|()| channels.next().flatten());
+        Some(Color::from_linear_rgba(r?, g?, b?, a?))
+    }
+
+    /// Used by [`crate::anim!`] macro
+    pub fn as_widget<'a, Message, Renderer>(
+        self,
+        timeline: &crate::Timeline,
+        content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>,
+    ) -> crate::widget::Background<'a, Message, Renderer> {
+        crate::widget::Background::new(content)
+            .color(self.color(timeline).unwrap_or(Color::TRANSPARENT))
+    }
+}
+
+/// A keyframe for a background color, interpolated in linear space.
+/// See [`crate::widget::Background`].
+#[must_use = "Keyframes are intended to be used in an animation chain."]
+#[derive(Debug, Clone, Copy)]
+pub struct Background {
+    at: MovementType,
+    ease: Ease,
+    color: [f32; 4],
+    is_eager: bool,
+}
+
+impl Background {
+    /// Create a new background keyframe, transparent.
+    pub fn new(at: impl Into<MovementType>) -> Background {
+        let at = at.into();
+        Background {
+            at,
+            ease: Linear::InOut.into(),
+            color: [0.0; 4],
+            is_eager: true,
+        }
+    }
+
+    /// Create a lazy background keyframe, that continues from the current color.
+    pub fn lazy(at: impl Into<MovementType>) -> Background {
+        let at = at.into();
+        Background {
+            at,
+            ease: Linear::InOut.into(),
+            color: [0.0; 4],
+            is_eager: false,
+        }
+    }
+
+    /// The color at this keyframe, as red, green, blue and alpha.
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        let [r, g, b, a] = color;
+        self.color = Color::from_rgba(r, g, b, a).into_linear();
+        self
+    }
+
+    /// The ease used to animate into this keyframe.
+    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
+        self.ease = ease.into();
+        self
+    }
+}
+
+#[rustfmt::skip]
+impl From<Background> for Vec<Option<Frame>> {
+    fn from(background: Background) -> Vec<Option<Frame>> {
+      let [r, g, b, a] = background.color;
+      if background.is_eager {
+        vec![Some(Frame::eager(background.at, r, background.ease)), // 0 = red, in linear space
+             Some(Frame::eager(background.at, g, background.ease)), // 1 = green
+             Some(Frame::eager(background.at, b, background.ease)), // 2 = blue
+             Some(Frame::eager(background.at, a, background.ease)), // 3 = alpha
+        ]
+      } else {
+        vec![Some(Frame::lazy(background.at, 0., background.ease)), // lazy evaluates for all values
+             Some(Frame::lazy(background.at, 0., background.ease)),
+             Some(Frame::lazy(background.at, 0., background.ease)),
+             Some(Frame::lazy(background.at, 0., background.ease)),
+        ]
+      }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{background, chain, Duration, Instant, Timeline};
+
+    #[test]
+    fn blends_in_linear_space() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(chain!(
+                id,
+                background(Duration::ZERO).color([1.0, 0.0, 0.0, 1.0]),
+                background(Duration::from_millis(100)).color([0.0, 0.0, 1.0, 0.5]),
+            ))
+            .start_at(now);
+
+        timeline.now(now + Duration::from_millis(50));
+        let color = id.color(&timeline).unwrap();
+        // Halfway in linear space, red and blue are both brighter than 0.5.
+        assert!((color.r - 0.735).abs() < 1e-3);
+        assert_eq!(0.0, color.g);
+        assert!((color.b - 0.735).abs() < 1e-3);
+        assert!((color.a - 0.75).abs() < 1e-6);
+
+        timeline.now(now + Duration::from_millis(100));
+        let color = id.color(&timeline).unwrap();
+        assert!((color.b - 1.0).abs() < 1e-6 && color.r.abs() < 1e-6);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::reexports::iced_core::{text, Renderer as IcedRenderer};
+
+use crate::{chain, checkbox, lazy::checkbox as lazy, Duration, Ease, Linear};
+
+const ANIM_DURATION: f32 = 100.;
+
+keyframe_chain!(Checkbox, "a checkbox");
+
+impl Id {
+    /// How far the checkmark is drawn in, from `0.0` unchecked to `1.0`
+    /// checked. `None` if the checkbox was never animated.
+    #[must_use]
+    pub fn percent(&self, timeline: &crate::Timeline) -> Option<f32> {
+        timeline
+            .get(&self.clone().into(), 0)
+            .map(|interped| interped.value)
+    }
+
+    /// Used by [`crate::anim!`] macro
+    pub fn as_widget<'a, Message, Renderer, F>(
+        self,
+        timeline: &crate::Timeline,
+        label: impl Into<Option<String>>,
+        is_checked: bool,
+        f: F,
+    ) -> crate::widget::Checkbox<'a, Message, Renderer>
+    where
+        Renderer: IcedRenderer + text::Renderer,
+        F: 'a + Fn(Chain, bool) -> Message,
+    {
+        let percent = self
+            .percent(timeline)
+            .unwrap_or(if is_checked { 1.0 } else { 0.0 });
+        crate::widget::Checkbox::new(self, label, is_checked, f).percent(percent)
+    }
+}
+
+impl Chain {
+    /// Returns the default animation for checking the checkbox, drawing the
+    /// checkmark in over 100ms times `anim_multiplier`.
+    #[must_use]
+    pub fn on(id: Id, anim_multiplier: f32) -> Self {
+        let duration = (ANIM_DURATION * anim_multiplier) as u64;
+        Self::on_with(id, Duration::from_millis(duration), Linear::InOut)
+    }
+
+    /// Returns the default animation for unchecking the checkbox, erasing the
+    /// checkmark over 100ms times `anim_multiplier`.
+    #[must_use]
+    pub fn off(id: Id, anim_multiplier: f32) -> Self {
+        let duration = (ANIM_DURATION * anim_multiplier) as u64;
+        Self::off_with(id, Duration::from_millis(duration), Linear::InOut)
+    }
+
+    /// Returns an animation from wherever the checkmark currently is to fully
+    /// drawn, with a custom duration and ease. Starting from the current value
+    /// means a toggle interrupted mid-draw turns around with this ease.
+    #[must_use]
+    pub fn on_with(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            checkbox(duration).percent(1.0).ease(ease),
+        )
+    }
+
+    /// Returns an animation from wherever the checkmark currently is to erased,
+    /// with a custom duration and ease. See [`Chain::on_with`].
+    #[must_use]
+    pub fn off_with(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            checkbox(duration).percent(0.0).ease(ease),
+        )
+    }
+}
+
+keyframe!(
+    /// A keyframe for how far a checkbox's checkmark is drawn in, from `0.0`
+    /// unchecked to `1.0` checked. See [`crate::widget::Checkbox`].
+    Checkbox,
+    "checkbox",
+    /// How far the checkmark is drawn in at this keyframe.
+    percent: 1.0,
+    lazy 0.0,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Timeline};
+
+    #[test]
+    fn anim_multiplier() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+
+        assert_eq!(None, id.percent(&timeline));
+        // Half the default 100ms.
+        timeline.set_chain(Chain::on(id.clone(), 0.5)).start_at(now);
+        timeline.now(at(25));
+        assert_eq!(Some(0.5), id.percent(&timeline));
+        timeline.now(at(50));
+        assert_eq!(Some(1.0), id.percent(&timeline));
+
+        // Twice the default, erasing from the fully drawn checkmark.
+        timeline
+            .set_chain(Chain::off(id.clone(), 2.0))
+            .start_at(at(50));
+        timeline.now(at(150));
+        assert_eq!(Some(0.5), id.percent(&timeline));
+        timeline.now(at(250));
+        assert_eq!(Some(0.0), id.percent(&timeline));
+    }
+}
@@ -0,0 +1,174 @@
+use cosmic::widget::icon;
+
+use crate::timeline::Frame;
+use crate::{chain, frame_index, Duration, Ease, Linear, MovementType};
+
+keyframe_chain!(FrameIndex, "a frame index");
+
+impl Id {
+    /// Shows the handle at the animated index. Past the last handle, the
+    /// last handle is shown.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handles` is empty.
+    #[must_use]
+    pub fn as_widget(self, timeline: &crate::Timeline, handles: &[icon::Handle]) -> icon::Icon {
+        let index = timeline.get_index(&self.into()).unwrap_or(0);
+        cosmic::widget::icon(handles[index.min(handles.len() - 1)].clone())
+    }
+}
+
+impl Chain {
+    /// Returns an animation that shows `count` frames for `frame` each,
+    /// looping forever, like a sprite sheet.
+    #[must_use]
+    pub fn sequence(id: Id, count: usize, frame: Duration) -> Self {
+        chain!(
+            id,
+            frame_index(Duration::ZERO).index(0),
+            frame_index(frame * count as u32).index(count),
+        )
+        .loop_forever()
+    }
+
+    /// Returns an animation that counts up to `count`, `per_second` at a time,
+    /// like characters being typed. See [`crate::widget::typewriter`], which
+    /// counts characters, so pass `text.chars().count()` rather than
+    /// `text.len()`, which counts bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `per_second` isn't positive.
+    #[must_use]
+    pub fn reveal(id: Id, count: usize, per_second: f32) -> Self {
+        chain!(
+            id,
+            frame_index(Duration::ZERO).index(0),
+            frame_index(Duration::from_secs_f32(count as f32 / per_second)).index(count),
+        )
+    }
+}
+
+/// A keyframe for a discrete frame index, like the frames of a sprite sheet.
+/// The index is animated like any other value, and read back rounded down
+/// with [`crate::Timeline::get_index`], so each index is held until the next.
+#[must_use = "Keyframes are intended to be used in an animation chain."]
+#[derive(Debug, Clone, Copy)]
+pub struct FrameIndex {
+    at: MovementType,
+    ease: Ease,
+    index: f32,
+    is_eager: bool,
+}
+
+impl FrameIndex {
+    /// Create a new frame index keyframe.
+    pub fn new(at: impl Into<MovementType>) -> FrameIndex {
+        let at = at.into();
+        FrameIndex {
+            at,
+            ease: Linear::InOut.into(),
+            index: 0.0,
+            is_eager: true,
+        }
+    }
+
+    /// Create a lazy frame index keyframe, that continues from the current index.
+    pub fn lazy(at: impl Into<MovementType>) -> FrameIndex {
+        let at = at.into();
+        FrameIndex {
+            at,
+            ease: Linear::InOut.into(),
+            index: 0.0,
+            is_eager: false,
+        }
+    }
+
+    /// The frame index at this keyframe.
+    pub fn index(mut self, index: usize) -> Self {
+        self.index = index as f32;
+        self
+    }
+
+    /// The ease used to animate into this keyframe.
+    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
+        self.ease = ease.into();
+        self
+    }
+}
+
+#[rustfmt::skip]
+impl From<FrameIndex> for Vec<Option<Frame>> {
+    fn from(frame_index: FrameIndex) -> Vec<Option<Frame>> {
+      if frame_index.is_eager {
+        vec![Some(Frame::eager(frame_index.at, frame_index.index, frame_index.ease))]  // 0 = frame index
+      } else {
+        vec![Some(Frame::lazy(frame_index.at, 0., frame_index.ease))] // lazy evaluates for all values
+      }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Timeline};
+
+    #[test]
+    fn sequence() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(Chain::sequence(id.clone(), 3, Duration::from_millis(100)))
+            .start_at(now);
+
+        let id = id.into();
+        let mut index_at = |millis| {
+            timeline.now(now + Duration::from_millis(millis));
+            timeline.get_index(&id).unwrap()
+        };
+        assert_eq!(0, index_at(0));
+        assert_eq!(0, index_at(99));
+        assert_eq!(1, index_at(100));
+        assert_eq!(1, index_at(199));
+        assert_eq!(2, index_at(200));
+        assert_eq!(2, index_at(299));
+        // Wraps back to the first frame.
+        assert_eq!(0, index_at(300));
+        assert_eq!(1, index_at(433));
+    }
+
+    #[test]
+    fn index_boundaries() {
+        let now = Instant::now();
+        let index_at = |count, frame, at| {
+            let id = Id::unique();
+            let mut timeline = Timeline::new();
+            timeline
+                .set_chain(Chain::sequence(id.clone(), count, frame))
+                .start_at(now);
+            timeline.now(now + at);
+            timeline.get_index(&id.into()).unwrap()
+        };
+
+        // Each index starts exactly at its step, however many there are.
+        let millis = Duration::from_millis;
+        for step in [1, 999, 123_457, 999_999] {
+            assert_eq!(step, index_at(1_000_000, millis(1), millis(step as u64)));
+            assert_eq!(
+                step - 1,
+                index_at(1_000_000, millis(1), millis(step as u64 - 1))
+            );
+        }
+        // And not a moment before.
+        for step in [1, 50, 99] {
+            let boundary = millis(10 * step as u64);
+            assert_eq!(step, index_at(100, millis(10), boundary));
+            assert_eq!(
+                step - 1,
+                index_at(100, millis(10), boundary - Duration::from_micros(5))
+            );
+        }
+    }
+}
@@ -1,5 +1,19 @@
+use crate::keyframes::Accordion;
+use crate::keyframes::Background;
 use crate::keyframes::Cards;
+use crate::keyframes::Checkbox;
+use crate::keyframes::FrameIndex;
+use crate::keyframes::Offset;
+use crate::keyframes::Opacity;
+use crate::keyframes::Path;
+use crate::keyframes::Progress;
+use crate::keyframes::Rotate;
+use crate::keyframes::Scrollable;
+use crate::keyframes::Slider;
+use crate::keyframes::Text;
 use crate::keyframes::Toggler;
+use crate::keyframes::TwoState;
+use crate::keyframes::Value;
 
 use crate::MovementType;
 
@@ -15,10 +29,108 @@ pub fn cards(at: impl Into<MovementType>) -> Cards {
     Cards::new(at)
 }
 
+/// Create a frame index keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn frame_index(at: impl Into<MovementType>) -> FrameIndex {
+    FrameIndex::new(at)
+}
+
+/// Create an offset keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn offset(at: impl Into<MovementType>) -> Offset {
+    Offset::new(at)
+}
+
+/// Create an opacity keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn opacity(at: impl Into<MovementType>) -> Opacity {
+    Opacity::new(at)
+}
+
+/// Create a path keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn path(at: impl Into<MovementType>) -> Path {
+    Path::new(at)
+}
+
+/// Create a rotation keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn rotate(at: impl Into<MovementType>) -> Rotate {
+    Rotate::new(at)
+}
+
+/// Create a scrollable keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn scrollable(at: impl Into<MovementType>) -> Scrollable {
+    Scrollable::new(at)
+}
+
+/// Create a text keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn text(at: impl Into<MovementType>) -> Text {
+    Text::new(at)
+}
+
+/// Create a two-state keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn two_state(at: impl Into<MovementType>) -> TwoState {
+    TwoState::new(at)
+}
+
+/// Create a progress keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn progress(at: impl Into<MovementType>) -> Progress {
+    Progress::new(at)
+}
+
+/// Create a background keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn background(at: impl Into<MovementType>) -> Background {
+    Background::new(at)
+}
+
+/// Create a value keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn value(at: impl Into<MovementType>) -> Value {
+    Value::new(at)
+}
+
+/// Create a slider keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn slider(at: impl Into<MovementType>) -> Slider {
+    Slider::new(at)
+}
+
+/// Create a checkbox keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn checkbox(at: impl Into<MovementType>) -> Checkbox {
+    Checkbox::new(at)
+}
+
+/// Create an accordion keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn accordion(at: impl Into<MovementType>) -> Accordion {
+    Accordion::new(at)
+}
+
 /// A slightly different import to clean up makeing lazy keyframes.
 pub mod lazy {
+    use crate::keyframes::Accordion;
+    use crate::keyframes::Background;
     use crate::keyframes::Cards;
+    use crate::keyframes::Checkbox;
+    use crate::keyframes::FrameIndex;
+    use crate::keyframes::Offset;
+    use crate::keyframes::Opacity;
+    use crate::keyframes::Path;
+    use crate::keyframes::Progress;
+    use crate::keyframes::Rotate;
+    use crate::keyframes::Scrollable;
+    use crate::keyframes::Slider;
+    use crate::keyframes::Text;
     use crate::keyframes::Toggler;
+    use crate::keyframes::TwoState;
+    use crate::keyframes::Value;
     use crate::MovementType;
 
     /// Create a lazy toggler keyframe.
@@ -32,17 +144,129 @@ pub mod lazy {
     pub fn cards(at: impl Into<MovementType>) -> Cards {
         Cards::lazy(at)
     }
+
+    /// Create a lazy frame index keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn frame_index(at: impl Into<MovementType>) -> FrameIndex {
+        FrameIndex::lazy(at)
+    }
+
+    /// Create a lazy offset keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn offset(at: impl Into<MovementType>) -> Offset {
+        Offset::lazy(at)
+    }
+
+    /// Create a lazy opacity keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn opacity(at: impl Into<MovementType>) -> Opacity {
+        Opacity::lazy(at)
+    }
+
+    /// Create a lazy path keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn path(at: impl Into<MovementType>) -> Path {
+        Path::lazy(at)
+    }
+
+    /// Create a lazy rotation keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn rotate(at: impl Into<MovementType>) -> Rotate {
+        Rotate::lazy(at)
+    }
+
+    /// Create a lazy scrollable keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn scrollable(at: impl Into<MovementType>) -> Scrollable {
+        Scrollable::lazy(at)
+    }
+
+    /// Create a lazy text keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn text(at: impl Into<MovementType>) -> Text {
+        Text::lazy(at)
+    }
+
+    /// Create a lazy two-state keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn two_state(at: impl Into<MovementType>) -> TwoState {
+        TwoState::lazy(at)
+    }
+
+    /// Create a lazy progress keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn progress(at: impl Into<MovementType>) -> Progress {
+        Progress::lazy(at)
+    }
+
+    /// Create a lazy background keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn background(at: impl Into<MovementType>) -> Background {
+        Background::lazy(at)
+    }
+
+    /// Create a lazy value keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn value(at: impl Into<MovementType>) -> Value {
+        Value::lazy(at)
+    }
+
+    /// Create a lazy slider keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn slider(at: impl Into<MovementType>) -> Slider {
+        Slider::lazy(at)
+    }
+
+    /// Create a lazy checkbox keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn checkbox(at: impl Into<MovementType>) -> Checkbox {
+        Checkbox::lazy(at)
+    }
+
+    /// Create a lazy accordion keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn accordion(at: impl Into<MovementType>) -> Accordion {
+        Accordion::lazy(at)
+    }
 }
 
 /// A slightly different import to clean up makeing animation Ids.
 pub mod id {
+    pub use crate::keyframes::accordion::Id as Accordion;
+    pub use crate::keyframes::background::Id as Background;
     pub use crate::keyframes::cards::Id as Cards;
+    pub use crate::keyframes::checkbox::Id as Checkbox;
+    pub use crate::keyframes::frame_index::Id as FrameIndex;
+    pub use crate::keyframes::offset::Id as Offset;
+    pub use crate::keyframes::opacity::Id as Opacity;
+    pub use crate::keyframes::path::Id as Path;
+    pub use crate::keyframes::progress::Id as Progress;
+    pub use crate::keyframes::rotate::Id as Rotate;
+    pub use crate::keyframes::scrollable::Id as Scrollable;
+    pub use crate::keyframes::slider::Id as Slider;
+    pub use crate::keyframes::text::Id as Text;
     pub use crate::keyframes::toggler::Id as Toggler;
+    pub use crate::keyframes::two_state::Id as TwoState;
+    pub use crate::keyframes::value::Id as Value;
 }
 
 /// Direct access to `Chain`s for widget that may return an animation
 /// in a message.
 pub mod chain {
+    pub use crate::keyframes::accordion::Chain as Accordion;
+    pub use crate::keyframes::background::Chain as Background;
     pub use crate::keyframes::cards::Chain as Cards;
+    pub use crate::keyframes::checkbox::Chain as Checkbox;
+    pub use crate::keyframes::frame_index::Chain as FrameIndex;
+    pub use crate::keyframes::offset::Chain as Offset;
+    pub use crate::keyframes::opacity::Chain as Opacity;
+    pub use crate::keyframes::path::Chain as Path;
+    pub use crate::keyframes::progress::Chain as Progress;
+    pub use crate::keyframes::rotate::Chain as Rotate;
+    pub use crate::keyframes::scrollable::Chain as Scrollable;
+    pub use crate::keyframes::slider::Chain as Slider;
+    pub use crate::keyframes::text::Chain as Text;
     pub use crate::keyframes::toggler::Chain as Toggler;
+    pub use crate::keyframes::two_state::Chain as TwoState;
+    pub use crate::keyframes::value::Chain as Value;
 }
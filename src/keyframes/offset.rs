@@ -0,0 +1,283 @@
+use crate::reexports::iced_core::Vector;
+
+use crate::timeline::Frame;
+use crate::{chain, lazy::offset as lazy, offset, Duration, Ease, Linear, MovementType};
+
+keyframe_chain!(Offset, "an offset");
+
+impl Id {
+    /// The animated offset, in pixels. Apply it as a translation
+    /// when drawing the content being slid.
+    #[must_use]
+    pub fn offset(&self, timeline: &crate::Timeline) -> Option<Vector> {
+        timeline.get_vector(&self.clone().into())
+    }
+}
+
+impl Chain {
+    /// Returns an animation that slides content in, from `distance` pixels
+    /// past `edge` to where it belongs.
+    #[must_use]
+    pub fn slide_in(
+        id: Id,
+        edge: Edge,
+        distance: f32,
+        duration: Duration,
+        ease: impl Into<Ease>,
+    ) -> Self {
+        chain!(
+            id,
+            offset(Duration::ZERO).to(edge.offset(distance)),
+            offset(duration).ease(ease),
+        )
+    }
+
+    /// Returns an animation that slides content out, from wherever it
+    /// currently is to `distance` pixels past `edge`.
+    #[must_use]
+    pub fn slide_out(
+        id: Id,
+        edge: Edge,
+        distance: f32,
+        duration: Duration,
+        ease: impl Into<Ease>,
+    ) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            offset(duration).to(edge.offset(distance)).ease(ease),
+        )
+    }
+}
+
+/// The edge that content slides in from, or out to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    /// Slides along the x axis, to the left.
+    Left,
+    /// Slides along the x axis, to the right.
+    Right,
+    /// Slides along the y axis, upward.
+    Top,
+    /// Slides along the y axis, downward.
+    Bottom,
+}
+
+impl Edge {
+    /// The offset `distance` pixels past this edge.
+    #[must_use]
+    pub fn offset(self, distance: f32) -> Vector {
+        match self {
+            Edge::Left => Vector::new(-distance, 0.0),
+            Edge::Right => Vector::new(distance, 0.0),
+            Edge::Top => Vector::new(0.0, -distance),
+            Edge::Bottom => Vector::new(0.0, distance),
+        }
+    }
+}
+
+/// A keyframe for offsetting content, in pixels. Used to slide content
+/// in and out. Read the value back with [`Id::offset`].
+#[must_use = "Keyframes are intended to be used in an animation chain."]
+#[derive(Debug, Clone, Copy)]
+pub struct Offset {
+    at: MovementType,
+    ease: Ease,
+    x: f32,
+    y: f32,
+    is_eager: bool,
+    // Whether x and y are changes from the keyframe before.
+    is_relative: [bool; 2],
+    // Whether x and y were set, so that moving only one of them by some
+    // amount leaves the other where it was.
+    is_set: [bool; 2],
+}
+
+impl Offset {
+    /// Create a new offset keyframe.
+    pub fn new(at: impl Into<MovementType>) -> Offset {
+        let at = at.into();
+        Offset {
+            at,
+            ease: Linear::InOut.into(),
+            x: 0.0,
+            y: 0.0,
+            is_eager: true,
+            is_relative: [false; 2],
+            is_set: [false; 2],
+        }
+    }
+
+    /// Create a lazy offset keyframe, that continues from the current offset.
+    pub fn lazy(at: impl Into<MovementType>) -> Offset {
+        let at = at.into();
+        Offset {
+            at,
+            ease: Linear::InOut.into(),
+            x: 0.0,
+            y: 0.0,
+            is_eager: false,
+            is_relative: [false; 2],
+            is_set: [false; 2],
+        }
+    }
+
+    /// The horizontal offset at this keyframe.
+    pub fn x(mut self, x: f32) -> Self {
+        self.x = x;
+        self.is_relative[0] = false;
+        self.is_set[0] = true;
+        self
+    }
+
+    /// The vertical offset at this keyframe.
+    pub fn y(mut self, y: f32) -> Self {
+        self.y = y;
+        self.is_relative[1] = false;
+        self.is_set[1] = true;
+        self
+    }
+
+    /// The offset at this keyframe.
+    pub fn to(self, offset: Vector) -> Self {
+        self.x(offset.x).y(offset.y)
+    }
+
+    /// Moves `delta` pixels horizontally from the keyframe before this one,
+    /// or from the current offset if this keyframe comes first. If y isn't
+    /// set, it stays where it was.
+    pub fn x_by(mut self, delta: f32) -> Self {
+        self.x = delta;
+        self.is_relative[0] = true;
+        self.is_set[0] = true;
+        self.is_relative[1] |= !self.is_set[1];
+        self
+    }
+
+    /// Moves `delta` pixels vertically from the keyframe before this one,
+    /// or from the current offset if this keyframe comes first. If x isn't
+    /// set, it stays where it was.
+    pub fn y_by(mut self, delta: f32) -> Self {
+        self.y = delta;
+        self.is_relative[1] = true;
+        self.is_set[1] = true;
+        self.is_relative[0] |= !self.is_set[0];
+        self
+    }
+
+    /// Moves by `delta` from the keyframe before this one, or from the
+    /// current offset if this keyframe comes first.
+    pub fn by(self, delta: Vector) -> Self {
+        self.x_by(delta.x).y_by(delta.y)
+    }
+
+    /// The ease used to animate into this keyframe.
+    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
+        self.ease = ease.into();
+        self
+    }
+}
+
+#[rustfmt::skip]
+impl From<Offset> for Vec<Option<Frame>> {
+    fn from(offset: Offset) -> Vec<Option<Frame>> {
+      let frame = |value, is_relative| if is_relative {
+        Frame::relative(offset.at, value, offset.ease)
+      } else if offset.is_eager {
+        Frame::eager(offset.at, value, offset.ease)
+      } else {
+        Frame::lazy(offset.at, 0., offset.ease) // lazy evaluates for all values
+      };
+      vec![Some(frame(offset.x, offset.is_relative[0])), // 0 = x
+           Some(frame(offset.y, offset.is_relative[1])), // 1 = y
+      ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Timeline};
+
+    #[test]
+    fn slide_in() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(Chain::slide_in(
+                id.clone(),
+                Edge::Left,
+                100.0,
+                Duration::from_millis(100),
+                Linear::InOut,
+            ))
+            .start_at(now);
+        assert_eq!(Some(Vector::new(-100.0, 0.0)), id.offset(&timeline));
+        timeline.now(now + Duration::from_millis(50));
+        assert_eq!(Some(Vector::new(-50.0, 0.0)), id.offset(&timeline));
+        timeline.now(now + Duration::from_millis(100));
+        assert_eq!(Some(Vector::new(0.0, 0.0)), id.offset(&timeline));
+
+        // Then out the other way, from where it is.
+        timeline
+            .set_chain(Chain::slide_out(
+                id.clone(),
+                Edge::Bottom,
+                40.0,
+                Duration::from_millis(100),
+                Linear::InOut,
+            ))
+            .start_at(now + Duration::from_millis(100));
+        timeline.now(now + Duration::from_millis(150));
+        assert_eq!(Some(Vector::new(0.0, 20.0)), id.offset(&timeline));
+    }
+
+    #[test]
+    fn edges() {
+        assert_eq!(Vector::new(-100.0, 0.0), Edge::Left.offset(100.0));
+        assert_eq!(Vector::new(100.0, 0.0), Edge::Right.offset(100.0));
+        assert_eq!(Vector::new(0.0, -100.0), Edge::Top.offset(100.0));
+        assert_eq!(Vector::new(0.0, 100.0), Edge::Bottom.offset(100.0));
+    }
+
+    #[test]
+    fn by() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(chain!(
+                id,
+                offset(Duration::ZERO).x(10.0).y(10.0),
+                offset(Duration::from_millis(100)).x_by(20.0),
+                offset(Duration::from_millis(100)).by(Vector::new(-5.0, 5.0)),
+            ))
+            .start_at(now);
+        timeline.now(now + Duration::from_millis(100));
+        assert_eq!(Some(Vector::new(30.0, 10.0)), id.offset(&timeline));
+        timeline.now(now + Duration::from_millis(200));
+        assert_eq!(Some(Vector::new(25.0, 15.0)), id.offset(&timeline));
+    }
+
+    #[test]
+    fn by_one_axis() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(chain!(
+                id,
+                offset(Duration::ZERO).y(50.0),
+                offset(Duration::from_millis(100)).x_by(10.0),
+                // An axis that is set keeps its absolute value.
+                offset(Duration::from_millis(100)).y_by(-20.0).x(0.0),
+            ))
+            .start_at(now);
+        // Moving right by 10 leaves y where it was.
+        timeline.now(now + Duration::from_millis(100));
+        assert_eq!(Some(Vector::new(10.0, 50.0)), id.offset(&timeline));
+        timeline.now(now + Duration::from_millis(200));
+        assert_eq!(Some(Vector::new(0.0, 30.0)), id.offset(&timeline));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::reexports::iced_core::Element;
+
+use crate::{chain, lazy::opacity as lazy, opacity, Duration, Ease};
+
+keyframe_chain!(Opacity, "an opacity");
+
+impl Id {
+    /// The animated opacity, between `0.0` and `1.0`.
+    #[must_use]
+    pub fn opacity(&self, timeline: &crate::Timeline) -> Option<f32> {
+        timeline
+            .get(&self.clone().into(), 0)
+            .map(|interped| interped.value)
+    }
+
+    /// Used by [`crate::anim!`] macro
+    /// Only the content's text fades, see [`crate::widget::FadeText`].
+    pub fn as_widget<'a, Message, Renderer>(
+        self,
+        timeline: &crate::Timeline,
+        content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>,
+    ) -> crate::widget::FadeText<'a, Message, Renderer> {
+        crate::widget::FadeText::new(content).opacity(self.opacity(timeline).unwrap_or(1.0))
+    }
+}
+
+impl Chain {
+    /// Returns an animation from the current opacity to fully opaque.
+    #[must_use]
+    pub fn fade_in(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            opacity(duration).value(1.0).ease(ease),
+        )
+    }
+
+    /// Returns an animation from the current opacity to fully transparent.
+    #[must_use]
+    pub fn fade_out(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            opacity(duration).value(0.0).ease(ease),
+        )
+    }
+}
+
+keyframe!(
+    /// A keyframe for how opaque content is, from fully transparent at `0.0`
+    /// to fully opaque at `1.0`. See [`crate::widget::FadeText`].
+    Opacity,
+    "opacity",
+    /// The opacity at this keyframe. `0.0` is transparent, `1.0` is opaque.
+    value: 1.0,
+    lazy 1.0,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Linear, Timeline};
+
+    #[test]
+    fn fade_out_from_opaque() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+
+        // Content that has never faded is fully opaque, so that is where
+        // fading out starts.
+        assert_eq!(None, id.opacity(&timeline));
+        timeline
+            .set_chain(Chain::fade_out(
+                id.clone(),
+                Duration::from_millis(100),
+                Linear::InOut,
+            ))
+            .start_at(now);
+        assert_eq!(Some(1.0), id.opacity(&timeline));
+        timeline.now(at(25));
+        assert_eq!(Some(0.75), id.opacity(&timeline));
+        timeline.now(at(100));
+        assert_eq!(Some(0.0), id.opacity(&timeline));
+    }
+}
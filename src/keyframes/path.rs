@@ -0,0 +1,163 @@
+use crate::reexports::iced_core::Point;
+
+use crate::timeline::Frame;
+use crate::{path, Duration, Ease, Linear, MovementType};
+
+keyframe_chain!(Path, "a path");
+
+impl Id {
+    /// The animated position, in pixels.
+    #[must_use]
+    pub fn position(&self, timeline: &crate::Timeline) -> Option<Point> {
+        timeline
+            .get_vector(&self.clone().into())
+            .map(|vector| Point::ORIGIN + vector)
+    }
+
+    /// The point `t` of the way along the path, by distance.
+    /// See [`crate::Timeline::get_along_path`].
+    #[must_use]
+    pub fn along(&self, timeline: &crate::Timeline, t: f32) -> Option<Point> {
+        timeline.get_along_path(&self.clone().into(), t)
+    }
+}
+
+impl Chain {
+    /// Returns an animation that moves through `points` at a constant
+    /// speed, taking `duration` from the first point to the last.
+    #[must_use]
+    pub fn through(id: Id, points: impl IntoIterator<Item = Point>, duration: Duration) -> Self {
+        let points: Vec<Point> = points.into_iter().collect();
+        let length: f32 = points.windows(2).map(|w| w[0].distance(w[1])).sum();
+        let mut previous = None;
+        let links = points
+            .into_iter()
+            .map(|point| {
+                let at = match previous.replace(point) {
+                    Some(previous) if length > 0.0 => {
+                        duration.mul_f64(f64::from(point.distance(previous) / length))
+                    }
+                    _ => Duration::ZERO,
+                };
+                path(at).to(point)
+            })
+            .collect();
+        Chain::with_children(id, links)
+    }
+}
+
+/// A keyframe for a point along a path, in pixels. Read the position back
+/// with [`Id::position`], or by distance along the path with [`Id::along`].
+#[must_use = "Keyframes are intended to be used in an animation chain."]
+#[derive(Debug, Clone, Copy)]
+pub struct Path {
+    at: MovementType,
+    ease: Ease,
+    x: f32,
+    y: f32,
+    is_eager: bool,
+}
+
+impl Path {
+    /// Create a new path keyframe.
+    pub fn new(at: impl Into<MovementType>) -> Path {
+        let at = at.into();
+        Path {
+            at,
+            ease: Linear::InOut.into(),
+            x: 0.0,
+            y: 0.0,
+            is_eager: true,
+        }
+    }
+
+    /// Create a lazy path keyframe, that continues from the current position.
+    pub fn lazy(at: impl Into<MovementType>) -> Path {
+        let at = at.into();
+        Path {
+            at,
+            ease: Linear::InOut.into(),
+            x: 0.0,
+            y: 0.0,
+            is_eager: false,
+        }
+    }
+
+    /// The horizontal position at this keyframe.
+    pub fn x(mut self, x: f32) -> Self {
+        self.x = x;
+        self
+    }
+
+    /// The vertical position at this keyframe.
+    pub fn y(mut self, y: f32) -> Self {
+        self.y = y;
+        self
+    }
+
+    /// The position at this keyframe.
+    pub fn to(mut self, point: Point) -> Self {
+        self.x = point.x;
+        self.y = point.y;
+        self
+    }
+
+    /// The ease used to animate into this keyframe.
+    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
+        self.ease = ease.into();
+        self
+    }
+}
+
+#[rustfmt::skip]
+impl From<Path> for Vec<Option<Frame>> {
+    fn from(path: Path) -> Vec<Option<Frame>> {
+      if path.is_eager {
+        vec![Some(Frame::eager(path.at, path.x, path.ease)),  // 0 = x
+             Some(Frame::eager(path.at, path.y, path.ease)),  // 1 = y
+        ]
+      } else {
+        vec![Some(Frame::lazy(path.at, 0., path.ease)), // lazy evaluates for all values
+             Some(Frame::lazy(path.at, 0., path.ease)),
+        ]
+      }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Timeline};
+
+    #[test]
+    fn through() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        // 30 pixels right, then 10 down.
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(30.0, 0.0),
+            Point::new(30.0, 10.0),
+        ];
+        timeline
+            .set_chain(Chain::through(
+                id.clone(),
+                points,
+                Duration::from_millis(400),
+            ))
+            .start_at(now);
+
+        // Constant speed, so the corner is reached three quarters of the way in.
+        let mut position_at = |millis| {
+            timeline.now(at(millis));
+            id.position(&timeline)
+        };
+        assert_eq!(Some(Point::new(0.0, 0.0)), position_at(0));
+        assert_eq!(Some(Point::new(15.0, 0.0)), position_at(150));
+        assert_eq!(Some(Point::new(30.0, 0.0)), position_at(300));
+        assert_eq!(Some(Point::new(30.0, 5.0)), position_at(350));
+        assert_eq!(Some(Point::new(30.0, 10.0)), position_at(400));
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::RangeInclusive;
+
+use crate::{chain, lazy::progress as lazy, progress, Duration, Ease};
+
+keyframe_chain!(Progress, "a progress");
+
+impl Id {
+    /// The animated progress.
+    #[must_use]
+    pub fn value(&self, timeline: &crate::Timeline) -> Option<f32> {
+        timeline
+            .get(&self.clone().into(), 0)
+            .map(|interped| interped.value)
+    }
+
+    /// Used by [`crate::anim!`] macro
+    /// The animated value is clamped to `range`, so overshooting eases stop
+    /// at the ends of the bar. Without an animation, the bar is empty.
+    #[must_use]
+    pub fn as_widget<'a>(
+        self,
+        timeline: &crate::Timeline,
+        range: RangeInclusive<f32>,
+    ) -> cosmic::widget::ProgressBar<'a, cosmic::Theme> {
+        let value = self
+            .value(timeline)
+            .unwrap_or(*range.start())
+            .clamp(*range.start(), *range.end());
+        cosmic::widget::progress_bar(range, value)
+    }
+}
+
+impl Chain {
+    /// Returns an animation from the current progress to `value`, so the
+    /// bar eases toward each new value instead of snapping to it.
+    #[must_use]
+    pub fn to(id: Id, value: f32, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            progress(duration).value(value).ease(ease),
+        )
+    }
+}
+
+keyframe!(
+    /// A keyframe for a progress bar's value. See [`Id::as_widget`].
+    Progress,
+    "progress",
+    /// The progress at this keyframe, in the same range as the bar.
+    value: 0.0,
+    lazy 0.0,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Quadratic, Timeline};
+
+    #[test]
+    fn eases_toward_updates() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let to = |value| {
+            Chain::to(
+                id.clone(),
+                value,
+                Duration::from_millis(300),
+                Quadratic::Out,
+            )
+        };
+
+        // The first update starts from empty.
+        timeline.set_chain(to(10.0)).start_at(now);
+        timeline.now(at(300));
+        assert_eq!(Some(10.0), id.value(&timeline));
+
+        // An update mid-ease continues from the current value.
+        timeline.set_chain(to(20.0)).start_at(at(300));
+        timeline.now(at(450));
+        let halfway = id.value(&timeline).unwrap();
+        assert_eq!(17.5, halfway);
+        timeline.set_chain(to(30.0)).start_at(at(450));
+        assert_eq!(Some(halfway), id.value(&timeline));
+        timeline.now(at(750));
+        assert_eq!(Some(30.0), id.value(&timeline));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::reexports::iced_core::svg;
+
+use crate::{chain, rotate, Duration};
+
+keyframe_chain!(Rotate, "a rotation");
+
+impl Id {
+    /// The animated angle, in degrees clockwise.
+    #[must_use]
+    pub fn degrees(&self, timeline: &crate::Timeline) -> Option<f32> {
+        timeline
+            .get(&self.clone().into(), 0)
+            .map(|interped| interped.value)
+    }
+
+    /// Used by [`crate::anim!`] macro
+    #[must_use]
+    pub fn as_widget(
+        self,
+        timeline: &crate::Timeline,
+        handle: svg::Handle,
+    ) -> crate::widget::Rotate {
+        crate::widget::Rotate::new(handle).degrees(self.degrees(timeline).unwrap_or(0.0))
+    }
+}
+
+impl Chain {
+    /// Returns an animation that spins a full turn clockwise every `period`,
+    /// forever, like a loading spinner.
+    #[must_use]
+    pub fn spin(id: Id, period: Duration) -> Self {
+        chain!(
+            id,
+            rotate(Duration::ZERO).degrees(0.0),
+            rotate(period).degrees(360.0),
+        )
+        .loop_forever()
+    }
+}
+
+keyframe!(
+    /// A keyframe for the angle content is drawn at, in degrees clockwise
+    /// about its center. See [`crate::widget::Rotate`].
+    Rotate,
+    "rotation",
+    /// The angle at this keyframe, in degrees clockwise. Angles past `360.0`
+    /// keep turning, so `0.0` to `720.0` is two full turns.
+    degrees: 0.0,
+    lazy 0.0,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Timeline};
+
+    #[test]
+    fn spin() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(Chain::spin(id.clone(), Duration::from_secs(1)))
+            .start_at(now);
+
+        let mut degrees_at = |millis| {
+            timeline.now(now + Duration::from_millis(millis));
+            id.degrees(&timeline).unwrap()
+        };
+        assert_eq!(0.0, degrees_at(0));
+        assert_eq!(90.0, degrees_at(250));
+        // Keeps spinning, from a full turn back to the start.
+        assert_eq!(270.0, degrees_at(1750));
+        assert_eq!(180.0, degrees_at(10_500));
+    }
+}
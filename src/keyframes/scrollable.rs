@@ -0,0 +1,160 @@
+use crate::reexports::iced_core::widget::operation::{self, scrollable::AbsoluteOffset};
+use crate::reexports::iced_core::widget::{self as iced_widget, Operation};
+
+use crate::{chain, lazy::scrollable as lazy, scrollable, Duration, Ease};
+
+keyframe_chain!(Scrollable, "a scrollable");
+
+impl Id {
+    /// The animated vertical scroll offset, in pixels.
+    #[must_use]
+    pub fn offset(&self, timeline: &crate::Timeline) -> Option<f32> {
+        timeline
+            .get(&self.clone().into(), 0)
+            .map(|interped| interped.value)
+    }
+
+    /// The operation that scrolls the scrollable with the Id `target` to the
+    /// animated offset, if this animation exists. Run it each frame the
+    /// animation is running, like with `cosmic::iced_runtime::task::widget`
+    /// after [`crate::Timeline::now`].
+    #[must_use]
+    pub fn scroll_to<T>(
+        &self,
+        timeline: &crate::Timeline,
+        target: impl Into<iced_widget::Id>,
+    ) -> Option<impl Operation<T>> {
+        let offset = self.offset(timeline)?;
+        Some(operation::scrollable::scroll_to(
+            target.into(),
+            AbsoluteOffset { x: 0.0, y: offset },
+        ))
+    }
+}
+
+impl Chain {
+    /// Returns an animation that scrolls to `offset`, continuing from the
+    /// last animated offset. If the user may have scrolled since, start a
+    /// chain from the current offset with [`chain!`] instead.
+    #[must_use]
+    pub fn scroll_to(id: Id, offset: f32, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            scrollable(duration).offset(offset).ease(ease),
+        )
+    }
+}
+
+keyframe!(
+    /// A keyframe for a scrollable's vertical offset, in pixels.
+    /// Read the value back with [`Id::offset`], or scroll to it with
+    /// [`Id::scroll_to`].
+    Scrollable,
+    "scrollable",
+    /// The vertical scroll offset at this keyframe, in pixels.
+    offset: 0.0,
+    lazy 0.0,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reexports::iced_core::widget::operation::scrollable::{
+        RelativeOffset, Scrollable as ScrollableState,
+    };
+    use crate::reexports::iced_core::{Rectangle, Vector};
+    use crate::{Instant, Linear, Quadratic, Timeline};
+
+    #[test]
+    fn scroll_to() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+
+        assert_eq!(None, id.offset(&timeline));
+
+        timeline
+            .set_chain(Chain::scroll_to(
+                id.clone(),
+                400.0,
+                Duration::from_millis(200),
+                Linear::InOut,
+            ))
+            .start_at(now);
+        assert_eq!(Some(0.0), id.offset(&timeline));
+        timeline.now(at(50));
+        assert_eq!(Some(100.0), id.offset(&timeline));
+        timeline.now(at(200));
+        assert_eq!(Some(400.0), id.offset(&timeline));
+
+        // Scrolling again continues from the last offset.
+        timeline
+            .set_chain(Chain::scroll_to(
+                id.clone(),
+                0.0,
+                Duration::from_millis(100),
+                Quadratic::In,
+            ))
+            .start_at(at(300));
+        timeline.now(at(350));
+        assert_eq!(Some(300.0), id.offset(&timeline));
+        timeline.now(at(400));
+        assert_eq!(Some(0.0), id.offset(&timeline));
+    }
+
+    #[derive(Default)]
+    struct State(Option<AbsoluteOffset>);
+
+    impl ScrollableState for State {
+        fn snap_to(&mut self, _offset: RelativeOffset) {}
+
+        fn scroll_to(&mut self, offset: AbsoluteOffset) {
+            self.0 = Some(offset);
+        }
+
+        fn scroll_by(&mut self, _offset: AbsoluteOffset, _bounds: Rectangle, _content: Rectangle) {}
+    }
+
+    #[test]
+    fn scroll_operation() {
+        let id = Id::unique();
+        let target = iced_widget::Id::unique();
+        let other = iced_widget::Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+
+        assert!(id.scroll_to::<()>(&timeline, target.clone()).is_none());
+
+        timeline
+            .set_chain(Chain::scroll_to(
+                id.clone(),
+                400.0,
+                Duration::from_millis(200),
+                Linear::InOut,
+            ))
+            .start_at(now);
+        timeline.now(now + Duration::from_millis(50));
+        let mut operation = id.scroll_to::<()>(&timeline, target.clone()).unwrap();
+
+        // Only the target scrollable is scrolled.
+        let mut state = State::default();
+        operation.scrollable(
+            &mut state,
+            Some(&other),
+            Rectangle::default(),
+            Rectangle::default(),
+            Vector::ZERO,
+        );
+        assert_eq!(None, state.0);
+        operation.scrollable(
+            &mut state,
+            Some(&target),
+            Rectangle::default(),
+            Rectangle::default(),
+            Vector::ZERO,
+        );
+        assert_eq!(Some(AbsoluteOffset { x: 0.0, y: 100.0 }), state.0);
+    }
+}
@@ -0,0 +1,84 @@
+use std::ops::RangeInclusive;
+
+use crate::{chain, lazy::slider as lazy, slider, Duration, Ease};
+
+keyframe_chain!(Slider, "a slider");
+
+impl Id {
+    /// The animated value of the slider's handle.
+    #[must_use]
+    pub fn value(&self, timeline: &crate::Timeline) -> Option<f32> {
+        timeline
+            .get(&self.clone().into(), 0)
+            .map(|interped| interped.value)
+    }
+
+    /// Used by [`crate::anim!`] macro
+    /// The handle shows the animated value while there is an animation, and
+    /// `value` otherwise. Dragging the handle still calls `on_change`, but
+    /// the animation would keep overriding the handle, so when handling a
+    /// drag call [`crate::Timeline::clear_chain`] on this Id.
+    pub fn as_widget<'a, Message>(
+        self,
+        timeline: &crate::Timeline,
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: impl Fn(f32) -> Message + 'a,
+    ) -> cosmic::widget::Slider<'a, f32, Message, cosmic::Theme> {
+        let value = self
+            .value(timeline)
+            .unwrap_or(value)
+            .clamp(*range.start(), *range.end());
+        cosmic::widget::slider(range, value, on_change)
+    }
+}
+
+impl Chain {
+    /// Returns an animation from where the handle is now to `value`, for
+    /// when the value is set by the app, rather than by dragging.
+    #[must_use]
+    pub fn to(id: Id, value: f32, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            slider(duration).value(value).ease(ease),
+        )
+    }
+}
+
+keyframe!(
+    /// A keyframe for where a slider's handle is. See [`Id::as_widget`].
+    Slider,
+    "slider",
+    /// The value at this keyframe, in the same range as the slider.
+    value: 0.0,
+    lazy 0.0,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Quadratic, Timeline};
+
+    #[test]
+    fn drag_clears_animation() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(chain!(
+                id,
+                slider(Duration::ZERO).value(20.0),
+                slider(Duration::from_millis(200))
+                    .value(60.0)
+                    .ease(Quadratic::In),
+            ))
+            .start_at(now);
+        timeline.now(now + Duration::from_millis(100));
+        assert_eq!(Some(30.0), id.value(&timeline));
+
+        // Dragging hands the handle back to the app's value.
+        let _ = timeline.clear_chain(id.clone());
+        assert_eq!(None, id.value(&timeline));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::reexports::iced_core::Color;
+
+use crate::timeline::Frame;
+use crate::{Ease, Linear, MovementType};
+
+/// The size of a text keyframe that doesn't set one, the default body text size.
+const DEFAULT_SIZE: f32 = 14.0;
+
+keyframe_chain!(Text, "a text");
+
+impl Id {
+    /// The animated font size.
+    #[must_use]
+    pub fn size(&self, timeline: &crate::Timeline) -> Option<f32> {
+        timeline
+            .get(&self.clone().into(), 0)
+            .map(|interped| interped.value)
+    }
+
+    /// The animated color. `None` if no keyframe set a color.
+    #[must_use]
+    pub fn color(&self, timeline: &crate::Timeline) -> Option<Color> {
+        let id = self.clone().into();
+        let mut channels = (1..=4).map(|index| timeline.get(&id, index).map(|i| i.value));
+        let [r, g, b, a] = [(); 4].map(|()| channels.next().flatten());
+        Some(Color::from_linear_rgba(r?, g?, b?, a?))
+    }
+
+    /// Used by [`crate::anim!`] macro
+    pub fn as_widget<'a, Message: 'a>(
+        self,
+        timeline: &crate::Timeline,
+        content: impl Into<std::borrow::Cow<'a, str>>,
+    ) -> cosmic::Element<'a, Message> {
+        let mut text = cosmic::widget::text(content);
+        if let Some(size) = self.size(timeline) {
+            text = text.size(size);
+        }
+        if let Some(color) = self.color(timeline) {
+            text = text.class(cosmic::theme::Text::Color(color));
+        }
+        text.into()
+    }
+}
+
+/// A keyframe for a label's font size and color. The color is interpolated
+/// in linear space, and keyframes without one are skipped over when
+/// animating it. Read the values back with [`Id::size`] and [`Id::color`].
+#[must_use = "Keyframes are intended to be used in an animation chain."]
+#[derive(Debug, Clone, Copy)]
+pub struct Text {
+    at: MovementType,
+    ease: Ease,
+    size: f32,
+    color: Option<[f32; 4]>,
+    is_eager: bool,
+}
+
+impl Text {
+    /// Create a new text keyframe.
+    pub fn new(at: impl Into<MovementType>) -> Text {
+        let at = at.into();
+        Text {
+            at,
+            ease: Linear::InOut.into(),
+            size: DEFAULT_SIZE,
+            color: None,
+            is_eager: true,
+        }
+    }
+
+    /// Create a lazy text keyframe, that continues from the current size and
+    /// color. Its own values are only used if nothing is animating yet, and
+    /// the color is only continued if this keyframe has one.
+    pub fn lazy(at: impl Into<MovementType>) -> Text {
+        let at = at.into();
+        Text {
+            at,
+            ease: Linear::InOut.into(),
+            size: DEFAULT_SIZE,
+            color: None,
+            is_eager: false,
+        }
+    }
+
+    /// The font size at this keyframe.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// The color at this keyframe, as red, green, blue and alpha.
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        let [r, g, b, a] = color;
+        self.color = Some(Color::from_rgba(r, g, b, a).into_linear());
+        self
+    }
+
+    /// The ease used to animate into this keyframe.
+    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
+        self.ease = ease.into();
+        self
+    }
+}
+
+#[rustfmt::skip]
+impl From<Text> for Vec<Option<Frame>> {
+    fn from(text: Text) -> Vec<Option<Frame>> {
+      let frame = |value| if text.is_eager {
+        Frame::eager(text.at, value, text.ease)
+      } else {
+        Frame::lazy(text.at, value, text.ease) // lazy evaluates for all values
+      };
+      let color = text.color.map_or([None; 4], |color| color.map(|channel| Some(frame(channel))));
+      vec![Some(frame(text.size)), // 0 = size
+           color[0],                // 1 = red, in linear space
+           color[1],                // 2 = green
+           color[2],                // 3 = blue
+           color[3],                // 4 = alpha
+      ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{chain, text, Duration, Instant, Timeline};
+
+    #[test]
+    fn grow_and_fade_in() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(chain!(
+                id,
+                text(Duration::ZERO).size(10.0).color([0.0, 0.0, 0.0, 0.0]),
+                text(Duration::from_millis(100))
+                    .size(30.0)
+                    .color([1.0, 1.0, 1.0, 1.0]),
+            ))
+            .start_at(now);
+
+        timeline.now(now + Duration::from_millis(50));
+        assert_eq!(Some(20.0), id.size(&timeline));
+        let color = id.color(&timeline).unwrap();
+        // Halfway in linear space is brighter than halfway in sRGB.
+        assert!((color.r - 0.735).abs() < 1e-3);
+        assert_eq!(color.r, color.g);
+        assert!((color.a - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn size_only() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(chain!(
+                id,
+                text(Duration::ZERO),
+                text(Duration::from_millis(100)).size(28.0),
+            ))
+            .start_at(now);
+
+        timeline.now(now + Duration::from_millis(50));
+        assert_eq!(Some(21.0), id.size(&timeline));
+        assert_eq!(None, id.color(&timeline));
+    }
+}
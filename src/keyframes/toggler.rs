@@ -1,40 +1,12 @@
-use crate::reexports::iced_core::{text, widget::Id as IcedId, Renderer as IcedRenderer};
+use crate::reexports::iced_core::{text, Renderer as IcedRenderer};
 
-use crate::keyframes::Repeat;
-use crate::timeline::Frame;
-use crate::{chain, lazy::toggler as lazy, toggler, Duration, Ease, Linear, MovementType};
+use crate::{chain, lazy::toggler as lazy, toggler, Duration, Ease, Linear};
+
+keyframe_chain!(Toggler, "a toggler");
 
-/// A Toggler's animation Id. Used for linking animation built in `update()` with widget output in `view()`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(IcedId);
 const ANIM_DURATION: f32 = 100.;
 
 impl Id {
-    /// Creates a custom [`Id`].
-    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
-        Self(IcedId::new(id))
-    }
-
-    /// Creates a unique [`Id`].
-    ///
-    /// This function produces a different [`Id`] every time it is called.
-    #[must_use]
-    pub fn unique() -> Self {
-        Self(IcedId::unique())
-    }
-
-    /// Used by [`chain!`] macro
-    #[must_use]
-    pub fn into_chain(self) -> Chain {
-        Chain::new(self)
-    }
-
-    /// Used by [`chain!`] macro
-    #[must_use]
-    pub fn into_chain_with_children(self, children: Vec<Toggler>) -> Chain {
-        Chain::with_children(self, children)
-    }
-
     /// Used by [`crate::anim!`] macro
     pub fn as_widget<'a, Message, Renderer, F>(
         self,
@@ -51,138 +23,55 @@ impl Id {
     }
 }
 
-impl From<Id> for IcedId {
-    fn from(id: Id) -> Self {
-        id.0
-    }
-}
-
-#[derive(Debug, Clone)]
-/// An animation, where each keyframe is "chained" together.
-pub struct Chain {
-    id: Id,
-    links: Vec<Toggler>,
-    repeat: Repeat,
-}
-
 impl Chain {
-    /// Crate a new Toggler animation chain.
-    /// You probably don't want to use use directly, and should
-    /// use the [`chain!`] macro.
-    #[must_use]
-    pub fn new(id: Id) -> Self {
-        Chain {
-            id,
-            links: Vec::new(),
-            repeat: Repeat::Never,
-        }
-    }
-
-    /// Create a chain pre-fulled with children.
-    /// You probably don't want to use use directly, and should
-    /// use the [`chain!`] macro.
-    #[must_use]
-    pub fn with_children(id: Id, children: Vec<Toggler>) -> Self {
-        Chain {
-            id,
-            links: children,
-            repeat: Repeat::Never,
-        }
-    }
-
-    /// Link another keyframe, (very similar to push)
-    /// You probably don't want to use use directly, and should
-    /// use the [`chain!`] macro.
-    #[must_use]
-    pub fn link(mut self, toggler: Toggler) -> Self {
-        self.links.push(toggler);
-        self
-    }
-
-    /// Sets the animation to loop forever.
+    /// Returns the default animation for animating the toggler to "on"
     #[must_use]
-    pub fn loop_forever(mut self) -> Self {
-        self.repeat = Repeat::Forever;
-        self
+    pub fn on(id: Id, anim_multiplier: f32) -> Self {
+        let duration = (ANIM_DURATION * anim_multiplier.round()) as u64;
+        Self::on_with(id, Duration::from_millis(duration), Linear::InOut)
     }
 
-    /// Sets the animation to only loop once.
-    /// This is the default, and only useful to
-    /// stop an animation that was previously set
-    /// to loop forever.
+    /// Returns the default animation for animating the toggler to "off"
     #[must_use]
-    pub fn loop_once(mut self) -> Self {
-        self.repeat = Repeat::Never;
-        self
+    pub fn off(id: Id, anim_multiplier: f32) -> Self {
+        let duration = (ANIM_DURATION * anim_multiplier.round()) as u64;
+        Self::off_with(id, Duration::from_millis(duration), Linear::InOut)
     }
 
-    /// Returns the default animation for animating the toggler to "on"
+    /// Returns an animation from wherever the toggler currently is to "on",
+    /// with a custom duration and ease. Starting from the current value means
+    /// a toggle interrupted mid-travel turns around with this ease.
     #[must_use]
-    pub fn on(id: Id, anim_multiplier: f32) -> Self {
-        let duration = (ANIM_DURATION * anim_multiplier.round()) as u64;
+    pub fn on_with(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
         chain!(
             id,
             lazy(Duration::ZERO),
-            toggler(Duration::from_millis(duration)).percent(1.0),
+            toggler(duration).percent(1.0).ease(ease),
         )
     }
 
-    /// Returns the default animation for animating the toggler to "off"
+    /// Returns an animation from wherever the toggler currently is to "off",
+    /// with a custom duration and ease. See [`Chain::on_with`].
     #[must_use]
-    pub fn off(id: Id, anim_multiplier: f32) -> Self {
-        let duration = (ANIM_DURATION * anim_multiplier.round()) as u64;
+    pub fn off_with(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
         chain!(
             id,
             lazy(Duration::ZERO),
-            toggler(Duration::from_millis(duration)).percent(0.0),
+            toggler(duration).percent(0.0).ease(ease),
         )
     }
 }
 
-impl From<Chain> for crate::timeline::Chain {
-    fn from(chain: Chain) -> Self {
-        crate::timeline::Chain::new(
-            chain.id.into(),
-            chain.repeat,
-            chain
-                .links
-                .into_iter()
-                .map(std::convert::Into::into)
-                .collect::<Vec<_>>(),
-        )
-    }
-}
-
-#[must_use = "Keyframes are intended to be used in an animation chain."]
-#[derive(Debug, Clone, Copy)]
-pub struct Toggler {
-    at: MovementType,
-    ease: Ease,
-    percent: f32,
-    is_eager: bool,
-}
+keyframe!(
+    /// A keyframe for a toggler's switch, from off at `0.0` to on at `1.0`.
+    Toggler,
+    "toggler",
+    /// How far the switch is toggled on at this keyframe. `0.0` is off, `1.0` is on.
+    percent: 1.0,
+    lazy 0.0,
+);
 
 impl Toggler {
-    pub fn new(at: impl Into<MovementType>) -> Toggler {
-        let at = at.into();
-        Toggler {
-            at,
-            ease: Linear::InOut.into(),
-            percent: 1.0,
-            is_eager: true,
-        }
-    }
-
-    pub fn lazy(at: impl Into<MovementType>) -> Toggler {
-        let at = at.into();
-        Toggler {
-            at,
-            ease: Linear::InOut.into(),
-            percent: 1.0,
-            is_eager: false,
-        }
-    }
-
     pub fn as_widget<'a, Message, Renderer, F>(
         id: Id,
         timeline: &crate::Timeline,
@@ -200,25 +89,107 @@ impl Toggler {
                 .map_or(if is_toggled { 1.0 } else { 0.0 }, |m| m.value),
         )
     }
+}
 
-    pub fn percent(mut self, percent: f32) -> Self {
-        self.percent = percent;
-        self
-    }
-
-    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
-        self.ease = ease.into();
-        self
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reexports::iced_core::widget::Id as IcedId;
+    use crate::{Instant, Quadratic, Timeline};
+
+    #[test]
+    fn loop_smooth() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(
+                chain!(
+                    id,
+                    toggler(Duration::ZERO).percent(0.0),
+                    toggler(Duration::from_millis(100)).percent(1.0),
+                )
+                .loop_smooth(),
+            )
+            .start_at(now);
+
+        let id = IcedId::from(id);
+        let mut value_at = |millis| {
+            timeline.now(now + Duration::from_millis(millis));
+            (timeline.get(&id, 0).unwrap().value * 1000.0).round() / 1000.0
+        };
+        assert_eq!(1.0, value_at(100));
+        assert_eq!(0.5, value_at(150));
+        // No jump across the loop boundary.
+        assert_eq!(0.01, value_at(199));
+        assert_eq!(0.0, value_at(200));
+        assert_eq!(0.01, value_at(201));
+        assert_eq!(0.5, value_at(250));
+    }
+
+    #[test]
+    fn reversed() {
+        let open = || {
+            chain!(
+                Id::unique(),
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(Duration::from_millis(100)).percent(1.0),
+                toggler(Duration::from_millis(50))
+                    .percent(0.25)
+                    .ease(Quadratic::InOut),
+            )
+        };
+        let now = Instant::now();
+        let sample = |chain: Chain| {
+            let id = IcedId::from(chain.id.clone());
+            let mut timeline = Timeline::new();
+            timeline.set_chain(chain).start_at(now);
+            (0..=150)
+                .step_by(10)
+                .map(|millis| {
+                    timeline.now(now + Duration::from_millis(millis));
+                    timeline.get(&id, 0).unwrap().value
+                })
+                .collect::<Vec<_>>()
+        };
+        let forward = sample(open());
+        let backward = sample(open().reversed());
+        for (forward, backward) in forward.iter().zip(backward.iter().rev()) {
+            assert!((forward - backward).abs() < 1e-5);
+        }
     }
-}
 
-#[rustfmt::skip]
-impl From<Toggler> for Vec<Option<Frame>> {
-    fn from(toggler: Toggler) -> Vec<Option<Frame>> {
-      if toggler.is_eager {
-        vec![Some(Frame::eager(toggler.at, toggler.percent, toggler.ease))]  // 0 = animation percent completion
-      } else {
-        vec![Some(Frame::lazy(toggler.at, 0., toggler.ease))] // lazy evaluates for all values
-      }
+    #[test]
+    fn interrupted_toggle() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        timeline
+            .set_chain(Chain::on_with(
+                id.clone(),
+                Duration::from_millis(100),
+                Linear::InOut,
+            ))
+            .start_at(now);
+        timeline.now(at(50));
+        assert_eq!(0.5, timeline.get(&id.clone().into(), 0).unwrap().value);
+
+        // Turning back halfway uses the "off" direction's duration and ease,
+        // starting from where the "on" animation was.
+        timeline
+            .set_chain(Chain::off_with(
+                id.clone(),
+                Duration::from_millis(200),
+                Quadratic::In,
+            ))
+            .start_at(at(50));
+        let mut value_at = |millis| {
+            timeline.now(at(millis));
+            timeline.get(&id.clone().into(), 0).unwrap().value
+        };
+        assert_eq!(0.5, value_at(50));
+        assert_eq!(0.375, value_at(150));
+        assert_eq!(0.0, value_at(250));
     }
 }
@@ -0,0 +1,79 @@
+use crate::{chain, lazy::two_state as lazy, two_state, Duration, Ease};
+
+keyframe_chain!(TwoState, "a two-state");
+
+impl Chain {
+    /// Returns an animation from wherever the widget currently is to "on".
+    #[must_use]
+    pub fn on(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            two_state(duration).percent(1.0).ease(ease),
+        )
+    }
+
+    /// Returns an animation from wherever the widget currently is to "off".
+    #[must_use]
+    pub fn off(id: Id, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            two_state(duration).percent(0.0).ease(ease),
+        )
+    }
+}
+
+keyframe!(
+    /// A keyframe for any widget that animates between two states, "off" at
+    /// `0.0` and "on" at `1.0`, like a checkbox or an accordion. Read the value
+    /// back with [`crate::Timeline::two_state`].
+    TwoState,
+    "two-state",
+    /// How far into the "on" state this keyframe is. `0.0` is off, `1.0` is on.
+    percent: 1.0,
+    lazy 0.0,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Linear, Quadratic, Timeline};
+
+    #[test]
+    fn on_off() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+
+        assert_eq!(None, timeline.two_state(&id.clone().into()));
+
+        timeline
+            .set_chain(Chain::on(
+                id.clone(),
+                Duration::from_millis(100),
+                Quadratic::In,
+            ))
+            .start_at(now);
+        timeline.now(at(50));
+        assert_eq!(Some(0.25), timeline.two_state(&id.clone().into()));
+        timeline.now(at(100));
+        assert_eq!(Some(1.0), timeline.two_state(&id.clone().into()));
+
+        // Interrupting with `off` continues from the current value.
+        timeline.now(at(150));
+        timeline
+            .set_chain(Chain::off(
+                id.clone(),
+                Duration::from_millis(100),
+                Linear::InOut,
+            ))
+            .start_at(at(150));
+        assert_eq!(Some(1.0), timeline.two_state(&id.clone().into()));
+        timeline.now(at(200));
+        assert_eq!(Some(0.5), timeline.two_state(&id.clone().into()));
+        timeline.now(at(250));
+        assert_eq!(Some(0.0), timeline.two_state(&id.into()));
+    }
+}
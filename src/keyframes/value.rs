@@ -0,0 +1,171 @@
+use crate::timeline::Frame;
+use crate::{chain, lazy::value as lazy, value, Duration, Ease, Linear, MovementType};
+
+keyframe_chain!(Value, "a value");
+
+impl Chain {
+    /// Returns an animation from the current value to `target`, so that
+    /// changing the target midway continues from wherever the value is.
+    #[must_use]
+    pub fn to(id: Id, target: f32, duration: Duration, ease: impl Into<Ease>) -> Self {
+        chain!(
+            id,
+            lazy(Duration::ZERO),
+            value(duration).to(target).ease(ease),
+        )
+    }
+}
+
+/// A keyframe for a plain number that isn't tied to a widget, like a camera
+/// pan or an audio gain. Read the value back with [`crate::Timeline::value`].
+#[must_use = "Keyframes are intended to be used in an animation chain."]
+#[derive(Debug, Clone, Copy)]
+pub struct Value {
+    at: MovementType,
+    ease: Ease,
+    value: f32,
+    is_eager: bool,
+    is_relative: bool,
+}
+
+impl Value {
+    /// Create a new value keyframe, at `0.0`.
+    pub fn new(at: impl Into<MovementType>) -> Value {
+        let at = at.into();
+        Value {
+            at,
+            ease: Linear::InOut.into(),
+            value: 0.0,
+            is_eager: true,
+            is_relative: false,
+        }
+    }
+
+    /// Create a lazy value keyframe, that continues from the current value,
+    /// or `0.0` if there is none.
+    pub fn lazy(at: impl Into<MovementType>) -> Value {
+        let at = at.into();
+        Value {
+            at,
+            ease: Linear::InOut.into(),
+            value: 0.0,
+            is_eager: false,
+            is_relative: false,
+        }
+    }
+
+    /// The number at this keyframe.
+    pub fn to(mut self, value: f32) -> Self {
+        self.value = value;
+        self.is_relative = false;
+        self
+    }
+
+    /// The number at this keyframe is `delta` more than at the keyframe
+    /// before it, or than the current value if this keyframe comes first.
+    pub fn by(mut self, delta: f32) -> Self {
+        self.value = delta;
+        self.is_relative = true;
+        self
+    }
+
+    /// The ease used to animate into this keyframe.
+    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
+        self.ease = ease.into();
+        self
+    }
+}
+
+#[rustfmt::skip]
+impl From<Value> for Vec<Option<Frame>> {
+    fn from(value: Value) -> Vec<Option<Frame>> {
+      if value.is_relative {
+        vec![Some(Frame::relative(value.at, value.value, value.ease))]  // 0 = value
+      } else if value.is_eager {
+        vec![Some(Frame::eager(value.at, value.value, value.ease))]
+      } else {
+        vec![Some(Frame::lazy(value.at, value.value, value.ease))] // lazy evaluates for all values
+      }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Instant, Linear, Quadratic, Timeline};
+
+    #[test]
+    fn sample_mid_chain() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+
+        assert_eq!(None, timeline.value(&id.clone().into()));
+        timeline
+            .set_chain(chain!(
+                id,
+                value(Duration::ZERO).to(10.0),
+                value(Duration::from_millis(100)).to(20.0),
+                value(Duration::from_millis(100))
+                    .to(0.0)
+                    .ease(Quadratic::In),
+            ))
+            .start_at(now);
+        timeline.now(at(50));
+        assert_eq!(Some(15.0), timeline.value(&id.clone().into()));
+        // Halfway through the eased keyframe, not halfway between its values.
+        timeline.now(at(150));
+        assert_eq!(Some(15.0), timeline.value(&id.into()));
+    }
+
+    #[test]
+    fn to_without_value() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        // A number that was never animated starts from zero.
+        timeline
+            .set_chain(Chain::to(
+                id.clone(),
+                -10.0,
+                Duration::from_millis(100),
+                Linear::InOut,
+            ))
+            .start_at(now);
+        assert_eq!(Some(0.0), timeline.value(&id.clone().into()));
+        timeline.now(now + Duration::from_millis(25));
+        assert_eq!(Some(-2.5), timeline.value(&id.into()));
+    }
+
+    #[test]
+    fn by() {
+        let id = Id::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        timeline
+            .set_chain(chain!(
+                id,
+                value(Duration::ZERO).to(50.0),
+                value(Duration::from_millis(100)).to(100.0),
+                value(Duration::from_millis(100)).by(20.0),
+            ))
+            .start_at(now);
+        timeline.now(at(200));
+        assert_eq!(Some(120.0), timeline.value(&id.clone().into()));
+
+        // First in the chain, the change is from the current value.
+        timeline
+            .set_chain(chain!(
+                id,
+                value(Duration::ZERO).by(0.0),
+                value(Duration::from_millis(100)).by(-40.0),
+            ))
+            .start_at(at(200));
+        timeline.now(at(250));
+        assert_eq!(Some(100.0), timeline.value(&id.clone().into()));
+        timeline.now(at(300));
+        assert_eq!(Some(80.0), timeline.value(&id.into()));
+    }
+}
@@ -35,7 +35,7 @@
 //! 2. Add at least one animation to your timeline. This can be done in your
 //!    Application's `new()` or `update()`, or both!
 //! ```ignore
-//! static CONTAINER: Lazy<id::Container> = Lazy::new(id::Container::unique);
+//! id!(CONTAINER: Container);
 //!
 //! let animation = chain![
 //!   CONTAINER,
@@ -46,11 +46,12 @@
 //!
 //! ```
 //! There are some different things here!
-//!   > static CONTAINER: Lazy<id::Container> = `Lazy::new(id::Container::unique`);
+//!   > id!(CONTAINER: Container);
 //!
 //!   Cosmic Time refers to each animation with an Id. We export our own, but they are
 //!   Identical to the widget Id's Iced uses for widget operations.
 //!   Each animatable widget needs an Id. And each Id can only refer to one animation.
+//!   The [`id!`] macro declares them as statics, made unique on first use.
 //!
 //!   > let animation = chain![
 //!
@@ -89,6 +90,8 @@
 //!   self.timeline.set_chain(animation2).start();
 //!   ```
 //!   That code will compile, but will result in the animations not being in sync.
+//!   The `chains!` macro sets any number of chains and starts them once for you:
+//!   `chains!(self.timeline, animation1, animation2)`
 //!
 //! 3. Add the Cosmic time Subscription
 //! ```ignore
@@ -120,6 +123,8 @@
 #![deny(
     missing_debug_implementations,
     missing_docs,
+    // Not forbidden, serde's derives allow `unused_extern_crates`.
+    rust_2018_idioms,
     unused_results,
     clippy::extra_unused_lifetimes,
     clippy::from_over_into,
@@ -127,7 +132,7 @@
     clippy::new_without_default,
     clippy::useless_conversion
 )]
-#![forbid(unsafe_code, rust_2018_idioms)]
+#![forbid(unsafe_code)]
 #![allow(
     clippy::cast_possible_truncation,
     clippy::cast_sign_loss,
@@ -144,8 +149,11 @@ pub mod widget;
 mod keyframes;
 mod utils;
 
-pub use crate::keyframes::{cards, chain, id, lazy, toggler};
-pub use crate::timeline::{Chain, Timeline};
+pub use crate::keyframes::{
+    accordion, background, cards, chain, checkbox, frame_index, id, lazy, offset, opacity, path,
+    progress, rotate, scrollable, slider, text, toggler, two_state, value, Edge,
+};
+pub use crate::timeline::{Animation, Chain, GetError, Keyframes, Timed, Timeline};
 
 pub use cosmic::iced::time::{Duration, Instant};
 
@@ -172,6 +180,27 @@ pub trait Tween: std::fmt::Debug + Copy {
     /// Takes a linear percentage, and returns tweened value.
     /// p = percent complete as decimal
     fn tween(&self, p: f32) -> f32;
+
+    /// Evenly spaced `(p, tween(p))` points from `p = 0` to `p = 1`, like
+    /// for plotting the curve. Fewer than two `steps` only sample `p = 0`.
+    fn sample(&self, steps: usize) -> Vec<(f32, f32)> {
+        let last = steps.saturating_sub(1).max(1) as f32;
+        (0..steps)
+            .map(|i| {
+                let p = i as f32 / last;
+                (p, self.tween(p))
+            })
+            .collect()
+    }
+
+    /// Like [`Tween::sample`], but only writes the tweened values into
+    /// `out`, with as many evenly spaced points as `out` is long.
+    fn sample_into(&self, out: &mut [f32]) {
+        let last = out.len().saturating_sub(1).max(1) as f32;
+        for (i, value) in out.iter_mut().enumerate() {
+            *value = self.tween(i as f32 / last);
+        }
+    }
 }
 
 /// Speed Controlled Animation use this type.
@@ -181,6 +210,7 @@ pub trait Tween: std::fmt::Debug + Copy {
 /// Very useful with lazy keyframes.
 /// Designed to have an API very similar to `std::time::Duration`
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Speed {
     /// Whole number of seconds to move per second.
     PerSecond(f32),
@@ -218,26 +248,22 @@ impl Speed {
     }
 
     fn calc_duration(self, first: f32, second: f32) -> Duration {
-        match self {
-            Speed::PerSecond(speed) => {
-                ((first - second).abs() / speed).round() as u32 * Duration::from_nanos(1e9 as u64)
-            }
-            Speed::PerMillis(speed) => {
-                ((first - second).abs() / speed).round() as u32 * Duration::from_nanos(1e6 as u64)
-            }
-            Speed::PerMicros(speed) => {
-                ((first - second).abs() / speed).round() as u32 * Duration::from_nanos(1000)
-            }
-            Speed::PerNanoSe(speed) => {
-                ((first - second).abs() / speed).round() as u32 * Duration::from_nanos(1)
-            }
-        }
+        let (speed, nanos_per_unit) = match self {
+            Speed::PerSecond(speed) => (speed, 1e9),
+            Speed::PerMillis(speed) => (speed, 1e6),
+            Speed::PerMicros(speed) => (speed, 1e3),
+            Speed::PerNanoSe(speed) => (speed, 1.0),
+        };
+        // Scale in nanoseconds, so fractions of a unit aren't rounded away.
+        let nanos = f64::from((first - second).abs()) / f64::from(speed) * nanos_per_unit;
+        Duration::from_nanos(nanos.round() as u64)
     }
 }
 
 /// A container type so that the API user can specify Either
 /// Time controlled animations, or speed controlled animations.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MovementType {
     /// Keyframe is time controlled.
     Duration(Duration),
@@ -260,12 +286,24 @@ impl From<Speed> for MovementType {
 macro_rules! tween {
     ($($x:ident),*) => {
         #[derive(Debug, Copy, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         /// A container type for all types of animations easings.
+        ///
+        /// Eases can be parsed from, and written as, names like `"linear"`,
+        /// `"quadratic-in"`, `"cubic-out"`, `"back-in-out"` or
+        /// `"cubic-bezier(0.42, 0, 0.58, 1)"`. Parsing also accepts the short
+        /// family names `quad`, `sine`, `expo`, `circ`, `quart` and `quint`,
+        /// `inout` for `in-out`, and the `"ease-in-out-quad"` order, in any case.
         pub enum Ease {
             $(
                 /// A container for $x
                 $x($x),
             )*
+            /// A user supplied easing function. Only plain functions, and
+            /// closures that don't capture anything, are supported, because
+            /// [`Ease`] must stay `Copy`. Functions can't be serialized.
+            #[cfg_attr(feature = "serde", serde(skip))]
+            Custom(fn(f32) -> f32),
         }
 
         impl Tween for Ease {
@@ -274,12 +312,117 @@ macro_rules! tween {
                     $(
                         Ease::$x(ease) => ease.tween(p),
                     )*
+                    Ease::Custom(ease) => ease(p),
+                }
+            }
+        }
+
+        impl std::fmt::Display for Ease {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        Ease::$x(ease) => std::fmt::Display::fmt(ease, f),
+                    )*
+                    // Functions have no name to write, or parse back.
+                    Ease::Custom(_) => write!(f, "custom"),
+                }
+            }
+        }
+
+        impl std::str::FromStr for Ease {
+            type Err = ParseEaseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let name = canonical_ease_name(s);
+                $(
+                    if let Ok(ease) = name.parse::<$x>() {
+                        return Ok(Ease::$x(ease));
+                    }
+                )*
+                Err(ParseEaseError(s.to_owned()))
+            }
+        }
+    };
+}
+
+/// Implements `Display` and `FromStr` for an easing with `In`, `Out`, and `InOut`,
+/// named like `"cubic-in"`, `"cubic-out"`, and `"cubic-in-out"`.
+macro_rules! ease_names {
+    ($($x:ident => $name:literal),*) => {
+        $(
+            impl std::fmt::Display for $x {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        $x::In => write!(f, concat!($name, "-in")),
+                        $x::Out => write!(f, concat!($name, "-out")),
+                        $x::InOut => write!(f, concat!($name, "-in-out")),
+                    }
                 }
             }
+
+            impl std::str::FromStr for $x {
+                type Err = ParseEaseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        concat!($name, "-in") => Ok($x::In),
+                        concat!($name, "-out") => Ok($x::Out),
+                        concat!($name, "-in-out") => Ok($x::InOut),
+                        _ => Err(ParseEaseError(s.to_owned())),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Short ease family names, and the names they stand for.
+const EASE_ALIASES: [(&str, &str); 6] = [
+    ("quad", "quadratic"),
+    ("sine", "sinusoidal"),
+    ("expo", "exponential"),
+    ("circ", "circular"),
+    ("quart", "quartic"),
+    ("quint", "quintic"),
+];
+
+/// Rewrites other common spellings of an ease's name to the one [`Ease`]
+/// writes, like `"ease-in-out-quad"` and `"quad-inout"` to `"quadratic-in-out"`.
+fn canonical_ease_name(s: &str) -> String {
+    let mut name = s.trim().to_ascii_lowercase();
+    if let Some(rest) = name.strip_prefix("ease-") {
+        let split = ["in-out-", "inout-", "in-", "out-"]
+            .into_iter()
+            .find_map(|direction| Some((direction, rest.strip_prefix(direction)?)));
+        if let Some((direction, family)) = split {
+            name = format!("{family}-{}", direction.trim_end_matches('-'));
         }
+    }
+    let Some((family, rest)) = name.split_once('-') else {
+        return name;
     };
+    let family = EASE_ALIASES
+        .into_iter()
+        .find_map(|(alias, family_name)| (alias == family).then_some(family_name))
+        .unwrap_or(family);
+    let rest = rest
+        .strip_prefix("inout")
+        .map_or_else(|| rest.to_owned(), |args| format!("in-out{args}"));
+    format!("{family}-{rest}")
+}
+
+/// The error returned when parsing an easing from an unknown name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEaseError(String);
+
+impl std::fmt::Display for ParseEaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown ease name: {:?}", self.0)
+    }
 }
 
+impl std::error::Error for ParseEaseError {}
+
 tween!(
     Linear,
     Quadratic,
@@ -291,12 +434,17 @@ tween!(
     Circular,
     Elastic,
     Back,
-    Bounce
+    Bounce,
+    Hold,
+    CubicBezier,
+    Steps,
+    Spring
 );
 
 /// Used to set a linear animation easing.
 /// The default for most animations.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Linear {
     /// Modeled after the line y = x
     InOut,
@@ -308,6 +456,12 @@ impl Tween for Linear {
     }
 }
 
+impl From<fn(f32) -> f32> for Ease {
+    fn from(ease: fn(f32) -> f32) -> Self {
+        Ease::Custom(ease)
+    }
+}
+
 impl From<Linear> for Ease {
     fn from(linear: Linear) -> Self {
         Ease::Linear(linear)
@@ -316,6 +470,7 @@ impl From<Linear> for Ease {
 
 /// Used to set a quadratic animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Quadratic {
     /// Modeled after the parabola y = x^2
     In,
@@ -325,7 +480,10 @@ pub enum Quadratic {
     /// y = (1/2)((2x)^2)             ; [0, 0.5)
     /// y = -(1/2)((2x-1)*(2x-3) - 1) ; [0.5, 1]
     InOut,
-    /// A Bezier Curve TODO
+    /// A quadratic Bezier curve from 0 to 1, with the control point's height
+    /// given in percent and clamped to `[0, 100]`.
+    /// y = 2x(1-x)(n/100) + x^2
+    /// `Bezier(50)` is linear, lower values ease in and higher values ease out.
     Bezier(i32),
 }
 
@@ -341,7 +499,10 @@ impl Tween for Quadratic {
                     (-2. * p.powi(2)) + p.mul_add(4., -1.)
                 }
             }
-            Quadratic::Bezier(_n) => p,
+            Quadratic::Bezier(n) => {
+                let control = (*n).clamp(0, 100) as f32 / 100.;
+                (2. * p * (1. - p)).mul_add(control, p.powi(2))
+            }
         }
     }
 }
@@ -354,6 +515,7 @@ impl From<Quadratic> for Ease {
 
 /// Used to set a cubic animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Cubic {
     /// Modeled after the cubic y = x^3
     In,
@@ -393,6 +555,7 @@ impl From<Cubic> for Ease {
 
 /// Used to set a quartic animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Quartic {
     /// Modeled after the quartic y = x^4
     In,
@@ -432,6 +595,7 @@ impl From<Quartic> for Ease {
 
 /// Used to set a quintic animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Quintic {
     /// Modeled after the quintic y = x^5
     In,
@@ -471,6 +635,7 @@ impl From<Quintic> for Ease {
 
 /// Used to set a sinusoildal animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Sinusoidal {
     /// Modeled after eighth sinusoidal wave y = 1 - cos((x * PI) / 2)
     In,
@@ -498,6 +663,7 @@ impl From<Sinusoidal> for Ease {
 
 /// Used to set an exponential animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Exponential {
     /// Modeled after the piecewise exponential
     /// y = 0            ; [0, 0]
@@ -555,6 +721,7 @@ impl From<Exponential> for Ease {
 
 /// Used to set an circular animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Circular {
     /// Modeled after shifted quadrant IV of unit circle. y = 1 - sqrt(1 - x^2)
     In,
@@ -590,6 +757,7 @@ impl From<Circular> for Ease {
 
 /// Used to set an elastic animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Elastic {
     /// Modeled after damped sin wave: y = sin(13×π/2 x)×2^(10 (x - 1))
     In,
@@ -601,6 +769,67 @@ pub enum Elastic {
     /// y = 2^(10 (2 x - 1) - 1) sin(13 π x) [0, 0.5]
     /// y = 1/2 (2 - 2^(-10 (2 x - 1)) sin(13 π x)) [0.5, 1]
     InOut,
+    /// Like [`Elastic::In`], with a custom amplitude and period.
+    /// See [`Elastic::in_with`].
+    InWith {
+        /// How far the wave swings, at least `1.0`.
+        amplitude: f32,
+        /// The length of one swing, as a fraction of the animation.
+        period: f32,
+    },
+    /// Like [`Elastic::Out`], with a custom amplitude and period.
+    /// See [`Elastic::out_with`].
+    OutWith {
+        /// How far the wave swings, at least `1.0`.
+        amplitude: f32,
+        /// The length of one swing, as a fraction of the animation.
+        period: f32,
+    },
+    /// Like [`Elastic::InOut`], with a custom amplitude and period.
+    /// See [`Elastic::in_out_with`].
+    InOutWith {
+        /// How far the wave swings, at least `1.0`.
+        amplitude: f32,
+        /// The length of one swing, as a fraction of each half of the animation.
+        period: f32,
+    },
+}
+
+impl Elastic {
+    /// An elastic ease in. [`Elastic::In`] has an amplitude of `1.0`, and a
+    /// period of `4/13`. A higher amplitude swings further, and a shorter
+    /// period swings more often.
+    #[must_use]
+    pub fn in_with(amplitude: f32, period: f32) -> Self {
+        Elastic::InWith { amplitude, period }
+    }
+
+    /// An elastic ease out. [`Elastic::Out`] has an amplitude of `1.0`, and a
+    /// period of `0.3`.
+    #[must_use]
+    pub fn out_with(amplitude: f32, period: f32) -> Self {
+        Elastic::OutWith { amplitude, period }
+    }
+
+    /// An elastic ease in, then out. [`Elastic::InOut`] has an amplitude of
+    /// `1.0`, and a period of `4/13`.
+    #[must_use]
+    pub fn in_out_with(amplitude: f32, period: f32) -> Self {
+        Elastic::InOutWith { amplitude, period }
+    }
+
+    /// The damped sine wave, `a 2^(-10 x) sin((x - s) 2π / period)`, that all of
+    /// the parameterized eases are made of.
+    fn wave(x: f32, amplitude: f32, period: f32) -> f32 {
+        let period = period.max(f32::EPSILON);
+        // The phase shift that starts the wave at 0.
+        let (amplitude, shift) = if amplitude <= 1. {
+            (1., period / 4.)
+        } else {
+            (amplitude, period / (2. * PI) * (1. / amplitude).asin())
+        };
+        amplitude * 2_f32.powf(-10. * x) * ((x - shift) * (2. * PI) / period).sin()
+    }
 }
 
 impl Tween for Elastic {
@@ -621,6 +850,17 @@ impl Tween for Elastic {
                     0.5 * (2. - 2_f32.powf(-20. * p + 10.) * (13. * PI * p).sin())
                 }
             }
+            _ if p <= 0. => 0.,
+            _ if p >= 1. => 1.,
+            Elastic::InWith { amplitude, period } => -Elastic::wave(1. - p, *amplitude, *period),
+            Elastic::OutWith { amplitude, period } => Elastic::wave(p, *amplitude, *period) + 1.,
+            Elastic::InOutWith { amplitude, period } => {
+                if p < 0.5 {
+                    -0.5 * Elastic::wave(1. - 2. * p, *amplitude, *period)
+                } else {
+                    0.5 * Elastic::wave(2. * p - 1., *amplitude, *period) + 1.
+                }
+            }
         }
     }
 }
@@ -633,6 +873,7 @@ impl From<Elastic> for Ease {
 
 /// Used to set a back animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Back {
     /// Modeled after the function: y = 2.70158 * x^3 + x^2 * (-1.70158)
     In,
@@ -642,6 +883,58 @@ pub enum Back {
     /// y = (2x)^2 * (1/2 * ((2.5949095 + 1) * 2x - 2.5949095)) [0, 0.5]
     /// y = 1/2 * ((2 x - 2)^2 * ((2.5949095 + 1) * (2x - 2) + 2.5949095) + 2) [0.5, 1]
     InOut,
+    /// Like [`Back::In`], with a custom overshoot. See [`Back::in_with`].
+    InWith(f32),
+    /// Like [`Back::Out`], with a custom overshoot. See [`Back::out_with`].
+    OutWith(f32),
+    /// Like [`Back::InOut`], with a custom overshoot. See [`Back::in_out_with`].
+    InOutWith(f32),
+}
+
+impl Back {
+    /// The overshoot of [`Back::In`] and [`Back::Out`], which pulls back by about 10%.
+    pub const OVERSHOOT: f32 = 1.70158;
+
+    /// Eases in, pulling back further for a higher `overshoot`.
+    /// y = (s + 1) * x^3 - s * x^2
+    #[must_use]
+    pub fn in_with(overshoot: f32) -> Self {
+        Back::InWith(overshoot)
+    }
+
+    /// Eases out, overshooting further for a higher `overshoot`.
+    /// y = 1 + (s + 1) (x - 1)^3 + s (x - 1)^2
+    #[must_use]
+    pub fn out_with(overshoot: f32) -> Self {
+        Back::OutWith(overshoot)
+    }
+
+    /// Eases in then out, with a higher `overshoot` pulling back and
+    /// overshooting further. Each half is scaled by 1.525, so the default
+    /// overshoot gives [`Back::InOut`].
+    #[must_use]
+    pub fn in_out_with(overshoot: f32) -> Self {
+        Back::InOutWith(overshoot)
+    }
+
+    fn ease_in(p: f32, s: f32) -> f32 {
+        (s + 1.) * p.powi(3) - s * p.powi(2)
+    }
+
+    fn ease_out(p: f32, s: f32) -> f32 {
+        let q: f32 = p - 1.;
+        1. + (s + 1.) * q.powi(3) + s * q.powi(2)
+    }
+
+    fn ease_in_out(p: f32, c: f32) -> f32 {
+        if p < 0.5 {
+            let q = 2. * p;
+            q.powi(2) * (0.5 * ((c + 1.) * q - c))
+        } else {
+            let q = 2. * p - 2.;
+            0.5 * (q.powi(2) * ((c + 1.) * q + c) + 2.)
+        }
+    }
 }
 
 impl Tween for Back {
@@ -652,16 +945,10 @@ impl Tween for Back {
                 let q: f32 = p - 1.;
                 1. + 2.70158 * q.powi(3) + 1.70158 * q.powi(2)
             }
-            Back::InOut => {
-                let c = 2.594_909_5;
-                if p < 0.5 {
-                    let q = 2. * p;
-                    q.powi(2) * (0.5 * ((c + 1.) * q - c))
-                } else {
-                    let q = 2. * p - 2.;
-                    0.5 * (q.powi(2) * ((c + 1.) * q + c) + 2.)
-                }
-            }
+            Back::InOut => Back::ease_in_out(p, 2.594_909_5),
+            Back::InWith(s) => Back::ease_in(p, *s),
+            Back::OutWith(s) => Back::ease_out(p, *s),
+            Back::InOutWith(s) => Back::ease_in_out(p, s * 1.525),
         }
     }
 }
@@ -674,6 +961,7 @@ impl From<Back> for Ease {
 
 /// Used to set a bounce animation easing.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Bounce {
     /// Bounce before animating in.
     In,
@@ -723,6 +1011,524 @@ impl From<Bounce> for Ease {
     }
 }
 
+/// Used to hold the previous value, then snap to the next one.
+/// Useful for a "wait then jump" link inside an otherwise smooth chain.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Hold {
+    /// Holds the previous value until the very end of the link.
+    /// y = 0 ; [0, 1)
+    /// y = 1 ; [1, 1]
+    End,
+}
+
+impl Tween for Hold {
+    fn tween(&self, p: f32) -> f32 {
+        if p < 1. {
+            0.
+        } else {
+            1.
+        }
+    }
+}
+
+impl From<Hold> for Ease {
+    fn from(hold: Hold) -> Self {
+        Ease::Hold(hold)
+    }
+}
+
+/// A cubic Bezier curve from `(0, 0)` to `(1, 1)`, with two control points.
+/// The same as CSS's `cubic-bezier(x1, y1, x2, y2)`, so curves from a
+/// browser or design tool can be reused as is. `x1` and `x2` are clamped to
+/// `[0, 1]` so the curve stays a function of time, `y1` and `y2` may
+/// overshoot.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezier {
+    /// The x coordinate of the first control point.
+    pub x1: f32,
+    /// The y coordinate of the first control point.
+    pub y1: f32,
+    /// The x coordinate of the second control point.
+    pub x2: f32,
+    /// The y coordinate of the second control point.
+    pub y2: f32,
+}
+
+impl CubicBezier {
+    /// Creates a new [`CubicBezier`] from its two control points.
+    #[must_use]
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        CubicBezier { x1, y1, x2, y2 }
+    }
+}
+
+/// The polynomial coefficients of one axis of a cubic Bezier from 0 to 1.
+fn bezier_coefficients(c1: f64, c2: f64) -> (f64, f64, f64) {
+    let c = 3. * c1;
+    let b = 3. * (c2 - c1) - c;
+    (1. - c - b, b, c)
+}
+
+impl Tween for CubicBezier {
+    fn tween(&self, p: f32) -> f32 {
+        if p <= 0. {
+            return 0.;
+        } else if p >= 1. {
+            return 1.;
+        }
+        let x1 = f64::from(self.x1.clamp(0., 1.));
+        let x2 = f64::from(self.x2.clamp(0., 1.));
+        let (ax, bx, cx) = bezier_coefficients(x1, x2);
+        let (ay, by, cy) = bezier_coefficients(f64::from(self.y1), f64::from(self.y2));
+        let x_at = |t: f64| ((ax * t + bx) * t + cx) * t;
+        let p = f64::from(p);
+
+        // Newton-Raphson converges quickly, unless the slope is flat.
+        let mut t = p;
+        let mut solved = false;
+        for _ in 0..8 {
+            let error = x_at(t) - p;
+            if error.abs() < 1e-7 {
+                solved = true;
+                break;
+            }
+            let slope = (3. * ax * t + 2. * bx) * t + cx;
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            t -= error / slope;
+        }
+        // Otherwise, fall back to bisection, which always converges.
+        if !solved || !(0.0..=1.0).contains(&t) {
+            let (mut low, mut high) = (0., 1.);
+            t = p;
+            for _ in 0..64 {
+                let x = x_at(t);
+                if (x - p).abs() < 1e-7 {
+                    break;
+                }
+                if x < p {
+                    low = t;
+                } else {
+                    high = t;
+                }
+                t = (low + high) / 2.;
+            }
+        }
+        (((ay * t + by) * t + cy) * t) as f32
+    }
+}
+
+impl From<CubicBezier> for Ease {
+    fn from(cubic_bezier: CubicBezier) -> Self {
+        Ease::CubicBezier(cubic_bezier)
+    }
+}
+
+/// Which ends of a [`Steps`] ease jump, like CSS's `steps()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StepJump {
+    /// Jumps at the start, so the first plateau is already above 0.
+    Start,
+    /// Jumps at the end, so the last plateau is only reached at the end.
+    End,
+    /// Jumps at both ends, with plateaus between 0 and 1.
+    Both,
+    /// Doesn't jump at either end, the first plateau is 0 and the last is 1.
+    None,
+}
+
+/// A staircase ease, holding `count` plateaus instead of moving smoothly.
+/// Useful for flipping through sprite sheets or counting.
+/// A `count` of 0 is treated as 1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Steps {
+    /// How many plateaus the ease holds.
+    pub count: u32,
+    /// Which ends of the ease jump.
+    pub jump: StepJump,
+}
+
+impl Steps {
+    /// Creates a new [`Steps`] ease.
+    #[must_use]
+    pub fn new(count: u32, jump: StepJump) -> Self {
+        Steps { count, jump }
+    }
+}
+
+impl Tween for Steps {
+    fn tween(&self, p: f32) -> f32 {
+        if p <= 0. && self.jump != StepJump::Start && self.jump != StepJump::Both {
+            return 0.;
+        } else if p >= 1. {
+            return 1.;
+        }
+        let count = self.count.max(1);
+        let step = (p.max(0.) * count as f32).floor();
+        let (step, jumps) = match self.jump {
+            StepJump::Start => (step + 1., count),
+            StepJump::End => (step, count),
+            StepJump::Both => (step + 1., count + 1),
+            StepJump::None => (step, count.saturating_sub(1).max(1)),
+        };
+        (step / jumps as f32).clamp(0., 1.)
+    }
+}
+
+impl From<Steps> for Ease {
+    fn from(steps: Steps) -> Self {
+        Ease::Steps(steps)
+    }
+}
+
+/// A physically based spring, pulled from 0 to 1.
+/// Springs settle asymptotically, so the animation's time is mapped to the
+/// spring's [`Spring::settle_duration`], and the last moment snaps to 1.
+/// Use the settle duration as the keyframe's duration for realistic motion.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spring {
+    /// How strongly the spring pulls toward 1. Higher is faster.
+    pub stiffness: f32,
+    /// How strongly the motion is slowed. Below critical damping,
+    /// `2 * sqrt(stiffness * mass)`, the spring overshoots and oscillates.
+    pub damping: f32,
+    /// The mass on the spring. Higher is slower, and bouncier.
+    pub mass: f32,
+}
+
+/// How close to 1 a spring must stay to be settled.
+const SPRING_SETTLED: f64 = 1e-3;
+
+impl Spring {
+    /// Creates a new [`Spring`].
+    #[must_use]
+    pub fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Spring {
+            stiffness,
+            damping,
+            mass,
+        }
+    }
+
+    /// The natural angular frequency and the damping ratio.
+    fn dynamics(&self) -> (f64, f64) {
+        let stiffness = f64::from(self.stiffness).max(f64::EPSILON);
+        let mass = f64::from(self.mass).max(f64::EPSILON);
+        let damping = f64::from(self.damping).max(0.);
+        let omega = (stiffness / mass).sqrt();
+        (omega, damping / (2. * (stiffness * mass).sqrt()))
+    }
+
+    /// The spring's position, `t` seconds after release.
+    fn position(&self, t: f64) -> f64 {
+        let (omega, zeta) = self.dynamics();
+        if (zeta - 1.).abs() < 1e-4 {
+            // Critically damped
+            1. - (-omega * t).exp() * (1. + omega * t)
+        } else if zeta < 1. {
+            // Underdamped
+            let omega_d = omega * (1. - zeta * zeta).sqrt();
+            let decay = (-zeta * omega * t).exp();
+            1. - decay * ((omega_d * t).cos() + zeta * omega / omega_d * (omega_d * t).sin())
+        } else {
+            // Overdamped
+            let root = (zeta * zeta - 1.).sqrt();
+            let (slow, fast) = (-omega * (zeta - root), -omega * (zeta + root));
+            1. - (fast * (slow * t).exp() - slow * (fast * t).exp()) / (fast - slow)
+        }
+    }
+
+    /// Estimates how long the spring takes to settle, staying within 0.1% of 1.
+    /// An undamped spring never settles, so this is capped at an hour.
+    #[must_use]
+    pub fn settle_duration(&self) -> Duration {
+        let (omega, zeta) = self.dynamics();
+        // The time when the envelope of the motion falls to `SPRING_SETTLED`.
+        let secs = if (zeta - 1.).abs() < 1e-4 {
+            // Solve (1 + u) e^-u = settled, with Newton's method.
+            let mut u = -SPRING_SETTLED.ln();
+            for _ in 0..8 {
+                let f = (1. + u).ln() - u - SPRING_SETTLED.ln();
+                u -= f / (1. / (1. + u) - 1.);
+            }
+            u / omega
+        } else if zeta < 1. {
+            (1. / (SPRING_SETTLED * (1. - zeta * zeta).sqrt())).ln() / (zeta * omega)
+        } else {
+            let root = (zeta * zeta - 1.).sqrt();
+            let (slow, fast) = (omega * (zeta - root), omega * (zeta + root));
+            ((slow + fast) / ((fast - slow) * SPRING_SETTLED)).ln() / slow
+        };
+        Duration::from_secs_f64(secs.clamp(0., 3600.))
+    }
+}
+
+impl Tween for Spring {
+    fn tween(&self, p: f32) -> f32 {
+        if p <= 0. {
+            return 0.;
+        } else if p >= 1. {
+            return 1.;
+        }
+        let t = f64::from(p) * self.settle_duration().as_secs_f64();
+        self.position(t) as f32
+    }
+}
+
+impl From<Spring> for Ease {
+    fn from(spring: Spring) -> Self {
+        Ease::Spring(spring)
+    }
+}
+
+ease_names!(
+    Cubic => "cubic",
+    Quartic => "quartic",
+    Quintic => "quintic",
+    Sinusoidal => "sinusoidal",
+    Exponential => "exponential",
+    Circular => "circular",
+    Bounce => "bounce"
+);
+
+impl std::fmt::Display for Linear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Linear::InOut => write!(f, "linear"),
+        }
+    }
+}
+
+impl std::str::FromStr for Linear {
+    type Err = ParseEaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linear" => Ok(Linear::InOut),
+            _ => Err(ParseEaseError(s.to_owned())),
+        }
+    }
+}
+
+impl std::fmt::Display for Quadratic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Quadratic::In => write!(f, "quadratic-in"),
+            Quadratic::Out => write!(f, "quadratic-out"),
+            Quadratic::InOut => write!(f, "quadratic-in-out"),
+            Quadratic::Bezier(n) => write!(f, "quadratic-bezier({n})"),
+        }
+    }
+}
+
+impl std::str::FromStr for Quadratic {
+    type Err = ParseEaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "quadratic-in" => Ok(Quadratic::In),
+            "quadratic-out" => Ok(Quadratic::Out),
+            "quadratic-in-out" => Ok(Quadratic::InOut),
+            _ => s
+                .strip_prefix("quadratic-bezier(")
+                .and_then(|n| n.strip_suffix(')'))
+                .and_then(|n| n.parse().ok())
+                .map(Quadratic::Bezier)
+                .ok_or_else(|| ParseEaseError(s.to_owned())),
+        }
+    }
+}
+
+impl std::fmt::Display for Elastic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Elastic::In => write!(f, "elastic-in"),
+            Elastic::Out => write!(f, "elastic-out"),
+            Elastic::InOut => write!(f, "elastic-in-out"),
+            Elastic::InWith { amplitude, period } => {
+                write!(f, "elastic-in({amplitude}, {period})")
+            }
+            Elastic::OutWith { amplitude, period } => {
+                write!(f, "elastic-out({amplitude}, {period})")
+            }
+            Elastic::InOutWith { amplitude, period } => {
+                write!(f, "elastic-in-out({amplitude}, {period})")
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Elastic {
+    type Err = ParseEaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "elastic-in" => Ok(Elastic::In),
+            "elastic-out" => Ok(Elastic::Out),
+            "elastic-in-out" => Ok(Elastic::InOut),
+            _ => {
+                if let Some([amplitude, period]) = parse_args(s, "elastic-in") {
+                    Ok(Elastic::in_with(amplitude, period))
+                } else if let Some([amplitude, period]) = parse_args(s, "elastic-out") {
+                    Ok(Elastic::out_with(amplitude, period))
+                } else if let Some([amplitude, period]) = parse_args(s, "elastic-in-out") {
+                    Ok(Elastic::in_out_with(amplitude, period))
+                } else {
+                    Err(ParseEaseError(s.to_owned()))
+                }
+            }
+        }
+    }
+}
+
+/// Parses the arguments of a parameterized ease, like `"name(1, 2.5)"`.
+fn parse_args<const N: usize>(s: &str, name: &str) -> Option<[f32; N]> {
+    let mut args = s
+        .strip_prefix(name)?
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .split(',')
+        .map(|arg| arg.trim().parse::<f32>().ok());
+    let mut parsed = [0.; N];
+    for arg in &mut parsed {
+        *arg = args.next()??;
+    }
+    args.next().is_none().then_some(parsed)
+}
+
+impl std::fmt::Display for Back {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Back::In => write!(f, "back-in"),
+            Back::Out => write!(f, "back-out"),
+            Back::InOut => write!(f, "back-in-out"),
+            Back::InWith(overshoot) => write!(f, "back-in({overshoot})"),
+            Back::OutWith(overshoot) => write!(f, "back-out({overshoot})"),
+            Back::InOutWith(overshoot) => write!(f, "back-in-out({overshoot})"),
+        }
+    }
+}
+
+impl std::str::FromStr for Back {
+    type Err = ParseEaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "back-in" => Ok(Back::In),
+            "back-out" => Ok(Back::Out),
+            "back-in-out" => Ok(Back::InOut),
+            _ => {
+                if let Some([overshoot]) = parse_args(s, "back-in") {
+                    Ok(Back::in_with(overshoot))
+                } else if let Some([overshoot]) = parse_args(s, "back-out") {
+                    Ok(Back::out_with(overshoot))
+                } else if let Some([overshoot]) = parse_args(s, "back-in-out") {
+                    Ok(Back::in_out_with(overshoot))
+                } else {
+                    Err(ParseEaseError(s.to_owned()))
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Spring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Spring {
+            stiffness,
+            damping,
+            mass,
+        } = self;
+        write!(f, "spring({stiffness}, {damping}, {mass})")
+    }
+}
+
+impl std::str::FromStr for Spring {
+    type Err = ParseEaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_args(s, "spring")
+            .map(|[stiffness, damping, mass]| Spring::new(stiffness, damping, mass))
+            .ok_or_else(|| ParseEaseError(s.to_owned()))
+    }
+}
+
+impl std::fmt::Display for Hold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hold::End => write!(f, "hold-end"),
+        }
+    }
+}
+
+impl std::str::FromStr for Hold {
+    type Err = ParseEaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hold-end" => Ok(Hold::End),
+            _ => Err(ParseEaseError(s.to_owned())),
+        }
+    }
+}
+
+impl std::fmt::Display for CubicBezier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let CubicBezier { x1, y1, x2, y2 } = self;
+        write!(f, "cubic-bezier({x1}, {y1}, {x2}, {y2})")
+    }
+}
+
+impl std::str::FromStr for CubicBezier {
+    type Err = ParseEaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_args(s, "cubic-bezier")
+            .map(|[x1, y1, x2, y2]| CubicBezier::new(x1, y1, x2, y2))
+            .ok_or_else(|| ParseEaseError(s.to_owned()))
+    }
+}
+
+impl std::fmt::Display for Steps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let jump = match self.jump {
+            StepJump::Start => "jump-start",
+            StepJump::End => "jump-end",
+            StepJump::Both => "jump-both",
+            StepJump::None => "jump-none",
+        };
+        write!(f, "steps({}, {jump})", self.count)
+    }
+}
+
+impl std::str::FromStr for Steps {
+    type Err = ParseEaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseEaseError(s.to_owned());
+        let args = s
+            .strip_prefix("steps(")
+            .and_then(|args| args.strip_suffix(')'))
+            .ok_or_else(error)?;
+        let (count, jump) = args.split_once(',').unwrap_or((args, "jump-end"));
+        let jump = match jump.trim() {
+            "jump-start" | "start" => StepJump::Start,
+            "jump-end" | "end" => StepJump::End,
+            "jump-both" => StepJump::Both,
+            "jump-none" => StepJump::None,
+            _ => return Err(error()),
+        };
+        let count = count.trim().parse().map_err(|_| error())?;
+        Ok(Steps::new(count, jump))
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::excessive_precision)]
@@ -1255,4 +2061,367 @@ mod test {
         assert_eq!(0.956_121, r(Bounce::InOut.tween(0.9)));
         assert_eq!(1.000_000, r(Bounce::InOut.tween(1.0)));
     }
+
+    #[test]
+    fn hold_end() {
+        assert_eq!(0.0, Hold::End.tween(0.0));
+        assert_eq!(0.0, Hold::End.tween(0.5));
+        assert_eq!(0.0, Hold::End.tween(0.9));
+        assert_eq!(0.0, Hold::End.tween(0.999));
+        assert_eq!(1.0, Hold::End.tween(1.0));
+    }
+
+    #[test]
+    fn ease_names() {
+        let eases: Vec<Ease> = vec![
+            Linear::InOut.into(),
+            Quadratic::In.into(),
+            Quadratic::Out.into(),
+            Quadratic::InOut.into(),
+            Quadratic::Bezier(3).into(),
+            Cubic::In.into(),
+            Cubic::Out.into(),
+            Cubic::InOut.into(),
+            Quartic::In.into(),
+            Quartic::Out.into(),
+            Quartic::InOut.into(),
+            Quintic::In.into(),
+            Quintic::Out.into(),
+            Quintic::InOut.into(),
+            Sinusoidal::In.into(),
+            Sinusoidal::Out.into(),
+            Sinusoidal::InOut.into(),
+            Exponential::In.into(),
+            Exponential::Out.into(),
+            Exponential::InOut.into(),
+            Circular::In.into(),
+            Circular::Out.into(),
+            Circular::InOut.into(),
+            Elastic::In.into(),
+            Elastic::Out.into(),
+            Elastic::InOut.into(),
+            Elastic::in_with(1.5, 0.4).into(),
+            Elastic::out_with(1.5, 0.4).into(),
+            Elastic::in_out_with(1.5, 0.4).into(),
+            Back::In.into(),
+            Back::Out.into(),
+            Back::InOut.into(),
+            Back::in_with(2.5).into(),
+            Back::out_with(2.5).into(),
+            Back::in_out_with(2.5).into(),
+            Bounce::In.into(),
+            Bounce::Out.into(),
+            Bounce::InOut.into(),
+            Hold::End.into(),
+            CubicBezier::new(0.25, 0.1, 0.25, 1.0).into(),
+            Steps::new(4, StepJump::Start).into(),
+            Steps::new(4, StepJump::End).into(),
+            Steps::new(4, StepJump::Both).into(),
+            Steps::new(4, StepJump::None).into(),
+            Spring::new(100.0, 5.0, 1.0).into(),
+        ];
+        for ease in eases {
+            let name = ease.to_string();
+            let parsed: Ease = name.parse().unwrap();
+            assert_eq!(name, parsed.to_string());
+            assert_eq!(ease.tween(0.3), parsed.tween(0.3));
+        }
+        assert_eq!("back-out", Ease::from(Back::Out).to_string());
+        assert_eq!("linear", Linear::InOut.to_string());
+        assert!("cubic-sideways".parse::<Ease>().is_err());
+    }
+
+    #[test]
+    fn ease_name_aliases() {
+        let parse = |name: &str| name.parse::<Ease>().map(|ease| ease.to_string());
+        assert_eq!(Ok("quadratic-in-out".to_owned()), parse("ease-in-out-quad"));
+        assert_eq!(Ok("quadratic-in".to_owned()), parse("quad-in"));
+        assert_eq!(Ok("cubic-out".to_owned()), parse("Cubic-Out"));
+        assert_eq!(Ok("back-in-out".to_owned()), parse("back-inout"));
+        assert_eq!(Ok("back-in-out(2)".to_owned()), parse("back-inout(2)"));
+        assert_eq!(Ok("bounce-out".to_owned()), parse("ease-out-bounce"));
+        assert_eq!(Ok("sinusoidal-in".to_owned()), parse("ease-in-sine"));
+        assert_eq!(Ok("linear".to_owned()), parse(" linear "));
+        assert_eq!(
+            Ok("steps(4, jump-end)".to_owned()),
+            parse("steps(4, jump-end)")
+        );
+
+        let error = "ease-sideways-quad".parse::<Ease>().unwrap_err();
+        assert_eq!(
+            "unknown ease name: \"ease-sideways-quad\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn speed_units_agree() {
+        let per_second = 250.0;
+        for distance in [0.0, 0.5, 1.0, 3.3, 100.0, 1000.0, 12_345.6] {
+            let expected = Speed::per_secs(per_second).calc_duration(0.0, distance);
+            for speed in [
+                Speed::per_millis(per_second / 1e3),
+                Speed::per_micros(per_second / 1e6),
+                Speed::per_nanos(per_second / 1e9),
+            ] {
+                let duration = speed.calc_duration(distance, 0.0);
+                let difference = duration.abs_diff(expected);
+                // Only f32 precision of the speed itself may differ.
+                assert!(
+                    difference <= expected / 1_000_000 + Duration::from_nanos(1),
+                    "{speed:?} over {distance}: {duration:?} != {expected:?}"
+                );
+            }
+        }
+        assert_eq!(
+            Duration::from_millis(1500),
+            Speed::per_secs(2.0).calc_duration(1.0, 4.0)
+        );
+        assert_eq!(
+            Duration::from_micros(500),
+            Speed::per_millis(2.0).calc_duration(0.0, 1.0)
+        );
+        assert_eq!(
+            Duration::from_nanos(2500),
+            Speed::per_micros(0.4).calc_duration(0.0, 1.0)
+        );
+        assert_eq!(
+            Duration::from_nanos(3),
+            Speed::per_nanos(1.0).calc_duration(0.0, 3.3)
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let line = Linear::InOut.sample(11);
+        assert_eq!(11, line.len());
+        for (i, (p, value)) in line.into_iter().enumerate() {
+            assert_eq!(i as f32 / 10.0, p);
+            assert_eq!(p, value);
+        }
+
+        let ease = Ease::from(Cubic::In);
+        let mut out = [0.0; 5];
+        ease.sample_into(&mut out);
+        assert_eq!([0.0, 0.015_625, 0.125, 0.421_875, 1.0], out);
+        assert_eq!(vec![(0.0, 0.0)], ease.sample(1));
+        assert!(ease.sample(0).is_empty());
+    }
+
+    #[test]
+    fn speed_sub_unit_move() {
+        // Less than a whole second of movement still takes some time.
+        let duration = Speed::per_secs(100.0).calc_duration(10.0, 13.0);
+        assert!(duration.abs_diff(Duration::from_millis(30)) < Duration::from_micros(1));
+        let duration = Speed::per_millis(2.0).calc_duration(0.0, 0.5);
+        assert_eq!(Duration::from_micros(250), duration);
+    }
+
+    #[test]
+    fn quadratic_bezier() {
+        let sample = |n| {
+            [0.0, 0.25, 0.5, 0.75, 1.0].map(|p| (Quadratic::Bezier(n).tween(p) * 1e4).round() / 1e4)
+        };
+        assert_eq!([0.0, 0.25, 0.5, 0.75, 1.0], sample(50));
+        assert_eq!([0.0, 0.0625, 0.25, 0.5625, 1.0], sample(0));
+        assert_eq!([0.0, 0.4375, 0.75, 0.9375, 1.0], sample(100));
+        // Out of range control points are clamped.
+        assert_eq!(sample(0), sample(-40));
+        assert_eq!(sample(100), sample(i32::MAX));
+        for n in [i32::MIN, -1, 0, 33, 100, 250, i32::MAX] {
+            assert_eq!(0.0, Quadratic::Bezier(n).tween(0.0));
+            assert_eq!(1.0, Quadratic::Bezier(n).tween(1.0));
+        }
+    }
+
+    #[test]
+    fn cubic_bezier() {
+        let within = |expected: f32, actual: f32| {
+            assert!((expected - actual).abs() < 1e-4, "{expected} != {actual}");
+        };
+        // CSS's ease-in-out.
+        let ease_in_out = CubicBezier::new(0.42, 0.0, 0.58, 1.0);
+        for (p, expected) in [
+            (0.1, 0.019722),
+            (0.25, 0.129162),
+            (0.5, 0.5),
+            (0.75, 0.870838),
+            (0.9, 0.980278),
+        ] {
+            within(expected, ease_in_out.tween(p));
+        }
+        // CSS's ease.
+        let ease = CubicBezier::new(0.25, 0.1, 0.25, 1.0);
+        for (p, expected) in [(0.1, 0.094796), (0.5, 0.802403), (0.9, 0.994316)] {
+            within(expected, ease.tween(p));
+        }
+        // Exact, and stable, at and near the ends.
+        for curve in [ease_in_out, ease, CubicBezier::new(1.0, 0.0, 0.0, 1.0)] {
+            assert_eq!(0.0, curve.tween(0.0));
+            assert_eq!(1.0, curve.tween(1.0));
+            within(0.0, curve.tween(1e-6));
+            within(1.0, curve.tween(1.0 - 1e-6));
+        }
+        // Control points that overshoot in y, and are out of range in x.
+        assert!(CubicBezier::new(0.3, 1.5, 0.7, 1.5).tween(0.5) > 1.0);
+        assert!(CubicBezier::new(-1.0, 0.0, 2.0, 1.0).tween(0.5).is_finite());
+
+        assert_eq!(
+            Ok(ease_in_out),
+            "cubic-bezier(0.42, 0, 0.58, 1)".parse::<CubicBezier>()
+        );
+        assert!("cubic-bezier(0.42, 0, 0.58)"
+            .parse::<CubicBezier>()
+            .is_err());
+        assert!("cubic-bezier(0.42, 0, 0.58, 1, 2)"
+            .parse::<CubicBezier>()
+            .is_err());
+    }
+
+    #[test]
+    fn steps() {
+        let sample =
+            |steps: Steps| [0.0, 0.24, 0.25, 0.5, 0.74, 0.75, 0.99, 1.0].map(|p| steps.tween(p));
+        assert_eq!(
+            [0.0, 0.0, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0],
+            sample(Steps::new(4, StepJump::End))
+        );
+        assert_eq!(
+            [0.25, 0.25, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0],
+            sample(Steps::new(4, StepJump::Start))
+        );
+        assert_eq!(
+            [0.2, 0.2, 0.4, 0.6, 0.6, 0.8, 0.8, 1.0],
+            sample(Steps::new(4, StepJump::Both))
+        );
+        assert_eq!(
+            [0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0, 1.0, 1.0, 1.0],
+            sample(Steps::new(4, StepJump::None))
+        );
+        // No steps to divide by is treated as a single step.
+        assert_eq!(
+            sample(Steps::new(1, StepJump::End)),
+            sample(Steps::new(0, StepJump::End))
+        );
+        assert_eq!(0.0, Steps::new(0, StepJump::None).tween(0.5));
+        for jump in [
+            StepJump::Start,
+            StepJump::End,
+            StepJump::Both,
+            StepJump::None,
+        ] {
+            assert_eq!(1.0, Steps::new(4, jump).tween(1.5));
+            assert!((0.0..=1.0).contains(&Steps::new(4, jump).tween(-0.5)));
+        }
+
+        assert_eq!(Ok(Steps::new(3, StepJump::End)), "steps(3)".parse());
+        assert_eq!(
+            Ok(Steps::new(3, StepJump::Start)),
+            "steps(3, start)".parse()
+        );
+        assert!("steps(3, sideways)".parse::<Steps>().is_err());
+    }
+
+    #[test]
+    fn custom() {
+        fn smoothstep(p: f32) -> f32 {
+            p * p * (3. - 2. * p)
+        }
+
+        let custom = Ease::Custom(|p| p * p);
+        for p in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+            assert_eq!(Quadratic::In.tween(p), custom.tween(p));
+        }
+        let ease: Ease = (smoothstep as fn(f32) -> f32).into();
+        assert_eq!(0.5, ease.tween(0.5));
+        assert_eq!("custom", ease.to_string());
+        assert!("custom".parse::<Ease>().is_err());
+    }
+
+    #[test]
+    fn elastic_with() {
+        // The defaults reproduce the fixed variants, so nothing shifts.
+        let same = |fixed: Elastic, with: Elastic| {
+            for p in [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0] {
+                let (fixed, with) = (fixed.tween(p), with.tween(p));
+                assert!((fixed - with).abs() < 1e-5, "{fixed} != {with} at {p}");
+            }
+        };
+        same(Elastic::Out, Elastic::out_with(1.0, 0.3));
+        same(Elastic::In, Elastic::in_with(1.0, 4. / 13.));
+        same(Elastic::InOut, Elastic::in_out_with(1.0, 4. / 13.));
+        assert_eq!(1.25, r(Elastic::out_with(1.0, 0.3).tween(0.1)));
+        assert_eq!(1.003_906, r(Elastic::out_with(1.0, 0.3).tween(0.7)));
+
+        // A higher amplitude swings further.
+        let peak = |ease: Elastic| {
+            (1..100)
+                .map(|i| ease.tween(i as f32 / 100.))
+                .fold(0., f32::max)
+        };
+        assert!(peak(Elastic::out_with(2.0, 0.3)) > peak(Elastic::Out));
+        for ease in [Elastic::in_with(2.0, 0.5), Elastic::out_with(0.5, 0.1)] {
+            assert_eq!(0.0, ease.tween(0.0));
+            assert_eq!(1.0, ease.tween(1.0));
+        }
+    }
+
+    #[test]
+    fn back_with() {
+        // The default overshoot reproduces the fixed variants, so nothing shifts.
+        for p in [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0] {
+            assert_eq!(r(Back::In.tween(p)), r(Back::in_with(1.70158).tween(p)));
+            assert_eq!(
+                r(Back::Out.tween(p)),
+                r(Back::out_with(Back::OVERSHOOT).tween(p))
+            );
+            assert_eq!(
+                r(Back::InOut.tween(p)),
+                r(Back::in_out_with(Back::OVERSHOOT).tween(p))
+            );
+        }
+        assert_eq!(-0.099_352, r(Back::in_with(1.70158).tween(0.4)));
+        assert_eq!(0.591_172, r(Back::in_with(1.70158).tween(0.9)));
+
+        // More overshoot pops further, and no overshoot is cubic.
+        assert!(Back::out_with(3.0).tween(0.6) > Back::Out.tween(0.6));
+        assert_eq!(Cubic::In.tween(0.3), Back::in_with(0.0).tween(0.3));
+    }
+
+    #[test]
+    fn spring() {
+        let samples = |spring: Spring| (0..=100).map(move |i| spring.tween(i as f32 / 100.));
+
+        // Underdamped, with a damping ratio of 0.25, overshoots then settles.
+        let bouncy = Spring::new(100.0, 5.0, 1.0);
+        assert!(samples(bouncy).fold(0., f32::max) > 1.4);
+        assert!(samples(bouncy).any(|y| y < 1.0 && y > 0.5));
+
+        // Critically damped never overshoots, and only ever moves forward.
+        let critical = Spring::new(100.0, 20.0, 1.0);
+        let critical_samples: Vec<f32> = samples(critical).collect();
+        assert!(critical_samples.iter().all(|y| (0.0..=1.0).contains(y)));
+        assert!(critical_samples.windows(2).all(|w| w[0] <= w[1]));
+
+        // Overdamped doesn't either.
+        assert!(samples(Spring::new(100.0, 60.0, 1.0)).all(|y| (0.0..=1.0).contains(&y)));
+
+        for spring in [bouncy, critical, Spring::new(100.0, 60.0, 1.0)] {
+            assert_eq!(0.0, spring.tween(0.0));
+            assert_eq!(1.0, spring.tween(1.0));
+            // Close enough to 1 just before the end that snapping isn't noticeable.
+            assert!((1.0 - spring.tween(0.999)).abs() < 2e-3);
+        }
+
+        // (1 + u) e^-u = 0.001 at u = 9.2335, for a natural frequency of 10.
+        let settle = critical.settle_duration().as_secs_f32();
+        assert!((settle - 0.92335).abs() < 1e-4, "{settle}");
+        // A stiffer spring settles sooner, a heavier one later.
+        assert!(Spring::new(400.0, 40.0, 1.0).settle_duration() < critical.settle_duration());
+        assert!(Spring::new(100.0, 5.0, 4.0).settle_duration() > bouncy.settle_duration());
+        assert_eq!(
+            Duration::from_secs(3600),
+            Spring::new(100.0, 0.0, 1.0).settle_duration()
+        );
+    }
 }
@@ -1,16 +1,20 @@
 mod imports {
     pub use cosmic::iced::time::{Duration, Instant};
-    pub use cosmic::iced_core::widget;
+    pub use cosmic::iced_core::{widget, Point, Size, Vector};
     pub use cosmic::iced_futures::subscription::Subscription;
 }
 
-use imports::{widget, Duration, Instant, Subscription};
+use imports::{widget, Duration, Instant, Point, Size, Subscription, Vector};
 
+use smallvec::{smallvec, SmallVec};
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::Arc;
 
 use crate::keyframes::Repeat;
-use crate::{lerp, Ease, MovementType, Tween};
+use crate::utils::FnvHashMap;
+use crate::{lerp, Ease, Linear, MovementType, Tween};
 
 /// This holds all the data for your animations.
 /// tracks: this holds all data for active animations
@@ -19,12 +23,50 @@ use crate::{lerp, Ease, MovementType, Tween};
 #[derive(Debug, Clone)]
 pub struct Timeline {
     // Hash map of widget::id to track, where each track is made of subtracks<isize>
-    tracks: HashMap<widget::Id, (Meta, Vec<Vec<SubFrame>>)>,
+    tracks: FnvHashMap<widget::Id, (Meta, Track)>,
     // Pending keyframes. Need to call `start` to finalize start time and move into `tracks`
-    pendings: HashMap<widget::Id, Pending>,
+    pendings: FnvHashMap<widget::Id, Pending>,
     // Global animation interp value. Use `timeline.now(instant)`, where instant is the value
     // passed from the `timeline.as_subscription` value.
     now: Option<Instant>,
+    // The `now` before the current one, for `Timeline::get_prev`.
+    prev_now: Option<Instant>,
+    // Step that animation time is rounded down to. `Duration::ZERO` disables quantization.
+    quantize: Duration,
+    // Start animations already finished, for users that prefer reduced motion.
+    // Holds the time it was enabled at, animations started before stop there.
+    reduced_motion: Option<Instant>,
+    // Where time comes from when it isn't given. `None` is the system clock.
+    clock: Option<Clock>,
+    // Whether reading before the first `now` has been warned about already.
+    warned: Warned,
+}
+
+// The keyframes of one animated value, sorted by time. Most chains are a
+// handful of keyframes of one or two values, so those stay inline.
+type SubFrames = SmallVec<[SubFrame; 4]>;
+// Each of a widget's animated values, like x and y.
+type Track = SmallVec<[SubFrames; 2]>;
+
+// A user supplied source of time. See `Timeline::with_clock`.
+#[derive(Clone)]
+struct Clock(Arc<dyn Fn() -> Instant + Send + Sync>);
+
+// Set once the timeline has warned that it was read before the first `now`,
+// so the warning isn't printed on every `view()`.
+#[derive(Debug, Default)]
+struct Warned(AtomicBool);
+
+impl Clone for Warned {
+    fn clone(&self) -> Self {
+        Warned(AtomicBool::new(self.0.load(atomic::Ordering::Relaxed)))
+    }
+}
+
+impl std::fmt::Debug for Clock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Clock")
+    }
 }
 
 impl std::default::Default for Timeline {
@@ -43,23 +85,185 @@ pub struct Chain {
     /// Should we loop this animation? This field decides that.
     pub repeat: Repeat,
     links: Vec<Vec<Option<Frame>>>,
+    delay: Duration,
 }
 
 impl Chain {
     /// Create a new chain.
     pub fn new(id: widget::Id, repeat: Repeat, links: impl Into<Vec<Vec<Option<Frame>>>>) -> Self {
         let links = links.into();
-        Chain { id, repeat, links }
+        Chain {
+            id,
+            repeat,
+            links,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Waits `delay` after the chain is started before playing it, showing
+    /// the first keyframe meanwhile.
+    #[must_use]
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Delays each chain `stagger` more than the one before it, so the first
+    /// plays right away, the second `stagger` later, and so on, like for
+    /// revealing the items of a list one by one. Any delay a chain already
+    /// has is kept, and added to.
+    pub fn stagger(
+        chains: impl IntoIterator<Item = impl Into<Chain>>,
+        stagger: Duration,
+    ) -> Vec<Chain> {
+        let mut delay = Duration::ZERO;
+        chains
+            .into_iter()
+            .map(|chain| {
+                let mut chain = chain.into();
+                chain.delay += delay;
+                delay += stagger;
+                chain
+            })
+            .collect()
+    }
+
+    /// Start a chain with keyframes placed at percentages of `total`, like
+    /// CSS `@keyframes` stops, rather than by the time between each link.
+    /// Add the stops with [`Timed::at_percent`].
+    pub fn timed(id: impl Into<widget::Id>, total: Duration) -> Timed {
+        Timed {
+            id: id.into(),
+            repeat: Repeat::Never,
+            total,
+            stops: Vec::new(),
+        }
+    }
+}
+
+/// A chain of keyframes placed at percentages of a total length. The first
+/// stop's values are held until it is reached, and the animation ends at
+/// the last stop. Build it with [`Chain::timed`].
+#[derive(Debug, Clone)]
+pub struct Timed {
+    id: widget::Id,
+    repeat: Repeat,
+    total: Duration,
+    stops: Vec<(f32, Vec<Option<Frame>>)>,
+}
+
+impl Timed {
+    /// Place a keyframe `percent` of the way through the animation. The
+    /// keyframe's own time is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `percent` isn't within `[0, 100]`, or is before the
+    /// previous stop.
+    #[must_use]
+    pub fn at_percent(mut self, percent: f32, keyframe: impl Into<Vec<Option<Frame>>>) -> Self {
+        let previous = self.stops.last().map_or(0.0, |(previous, _)| *previous);
+        assert!(
+            (previous..=100.0).contains(&percent),
+            "keyframe at {percent}% must be within {previous}% and 100%"
+        );
+        self.stops.push((percent, keyframe.into()));
+        self
+    }
+
+    /// Sets the animation to loop forever.
+    #[must_use]
+    pub fn loop_forever(mut self) -> Self {
+        self.repeat = Repeat::Forever;
+        self
+    }
+
+    /// Sets the animation to play `times` times, then hold its last keyframe.
+    #[must_use]
+    pub fn loop_n(mut self, times: u32) -> Self {
+        self.repeat = Repeat::Times(times);
+        self
+    }
+
+    /// Sets the animation to play forward, then backward, forever.
+    #[must_use]
+    pub fn ping_pong(mut self) -> Self {
+        self.repeat = Repeat::PingPong;
+        self
+    }
+}
+
+impl From<Timed> for Chain {
+    fn from(timed: Timed) -> Self {
+        let Timed {
+            id,
+            repeat,
+            total,
+            mut stops,
+        } = timed;
+        if let Some((first, frames)) = stops.first() {
+            if *first > 0.0 {
+                let hold = (0.0, frames.clone());
+                stops.insert(0, hold);
+            }
+        }
+        let mut previous = 0.0;
+        let links: Vec<_> = stops
+            .into_iter()
+            .map(|(percent, frames)| {
+                let duration = total.mul_f64(f64::from(percent - previous) / 100.0);
+                previous = percent;
+                frames
+                    .into_iter()
+                    .map(|frame| frame.map(|frame| frame.with_duration(duration)))
+                    .collect()
+            })
+            .collect();
+        Chain::new(id, repeat, links)
+    }
+}
+
+/// A [`Chain`] without its Id, so the animation can be stored and loaded,
+/// like from a config file. With the `serde` feature it can be serialized.
+/// Chains using [`Ease::Custom`] can't be serialized.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keyframes {
+    /// Should we loop this animation? This field decides that.
+    pub repeat: Repeat,
+    /// Each link's time, value, and ease, one per animated value of the widget.
+    pub links: Vec<Vec<Option<Frame>>>,
+    /// How long to wait after starting, before playing. See [`Chain::delay`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub delay: Duration,
+}
+
+impl Keyframes {
+    /// Turns the keyframes back into a [`Chain`] animating `id`.
+    pub fn into_chain(self, id: impl Into<widget::Id>) -> Chain {
+        Chain::new(id.into(), self.repeat, self.links).delay(self.delay)
+    }
+}
+
+impl From<Chain> for Keyframes {
+    fn from(chain: Chain) -> Self {
+        Keyframes {
+            repeat: chain.repeat,
+            links: chain.links,
+            delay: chain.delay,
+        }
     }
 }
 
 #[derive(Debug, Clone)]
 enum Pending {
-    Chain(Repeat, Vec<Vec<Option<Frame>>>, Pause),
+    Chain(Repeat, Vec<Vec<Option<Frame>>>, Pause, Duration),
     Pause,
     Resume,
     PauseAll,
     ResumeAll,
+    Reverse,
+    ReverseAll,
 }
 
 /// A Frame is the exact value of the modifier at a given time.
@@ -69,12 +273,17 @@ enum Pending {
 /// like "animate to width 10".
 /// A `Frame::Lazy` is for continueing a previous animation, either midway through
 /// the animation, or even after the animation was completed.
+/// A `Frame::Relative` is for changing by some amount, like "grow by 20", from
+/// the frame before it in the chain, or from the current value if it is first.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Frame {
     /// Keyframe time, !!VALUE AT TIME!!, ease type into value
     Eager(MovementType, f32, Ease),
     /// Keyframe time, !!DEFAULT FALLBACK VALUE!!, ease type into value
     Lazy(MovementType, f32, Ease),
+    /// Keyframe time, !!CHANGE FROM THE PREVIOUS VALUE!!, ease type into value
+    Relative(MovementType, f32, Ease),
 }
 
 impl Frame {
@@ -90,6 +299,12 @@ impl Frame {
         Frame::Lazy(movement_type, default, ease)
     }
 
+    /// Create a Relative Frame, `delta` away from the frame before it.
+    pub fn relative(movement_type: impl Into<MovementType>, delta: f32, ease: Ease) -> Self {
+        let movement_type = movement_type.into();
+        Frame::Relative(movement_type, delta, ease)
+    }
+
     /// You almost certainly do not need this function.
     /// Used in `timeline::start` to guarentee that we have the same
     /// time of an animation, not the API convinient [`MovementType`].
@@ -104,13 +319,28 @@ impl Frame {
     }
 
     /// You almost certainly do not need this function.
-    /// Converts a Lazy [`Frame`] to an Eager [`Frame`].
+    /// Converts a Lazy or Relative [`Frame`] to an Eager [`Frame`]. A Relative
+    /// frame is taken from the current value here, or from `0.0` if there is none.
     pub fn to_eager(&mut self, timeline: &Timeline, id: &widget::Id, index: usize) {
-        *self = if let Frame::Lazy(movement_type, default, ease) = *self {
-            let value = timeline.get(id, index).map_or(default, |i| i.value);
-            Frame::Eager(movement_type, value, ease)
-        } else {
-            *self
+        *self = match *self {
+            Frame::Lazy(movement_type, default, ease) => {
+                let value = timeline.get(id, index).map_or(default, |i| i.value);
+                Frame::Eager(movement_type, value, ease)
+            }
+            Frame::Relative(movement_type, delta, ease) => {
+                let value = timeline.get(id, index).map_or(0.0, |i| i.value);
+                Frame::Eager(movement_type, value + delta, ease)
+            }
+            Frame::Eager(..) => *self,
+        }
+    }
+
+    // The same frame, reached after `duration` instead.
+    fn with_duration(self, duration: Duration) -> Self {
+        match self {
+            Frame::Eager(_, value, ease) => Frame::Eager(duration.into(), value, ease),
+            Frame::Lazy(_, default, ease) => Frame::Lazy(duration.into(), default, ease),
+            Frame::Relative(_, delta, ease) => Frame::Relative(duration.into(), delta, ease),
         }
     }
 
@@ -128,7 +358,17 @@ impl Frame {
         match self {
             Frame::Eager(movement_type, value, _ease) => match movement_type {
                 MovementType::Duration(duration) => duration,
-                MovementType::Speed(speed) => speed.calc_duration(previous.get_value(), value),
+                MovementType::Speed(speed) => {
+                    let previous = previous.get_value();
+                    let duration = speed.calc_duration(previous, value);
+                    // A tiny distance can round down to no time at all. Keep it a
+                    // near-instant step, rather than two frames at the same instant.
+                    if duration.is_zero() && previous != value {
+                        Duration::from_nanos(1)
+                    } else {
+                        duration
+                    }
+                }
             },
             _ => panic!("Call 'to_eager' first"),
         }
@@ -149,6 +389,17 @@ pub struct Meta {
     pub length: Duration,
     /// Is the animation paused? This decides that.
     pub pause: Pause,
+    // How fast the animation plays, if not at normal speed.
+    rate: Option<Rate>,
+}
+
+// A playback rate, and the instant it was set at, both real and as the
+// animation saw it, so that changing the rate doesn't jump.
+#[derive(Clone, Copy, Debug)]
+struct Rate {
+    rate: f32,
+    real: Instant,
+    scaled: Instant,
 }
 
 impl Meta {
@@ -167,6 +418,7 @@ impl Meta {
             end,
             length,
             pause,
+            rate: None,
         }
     }
 
@@ -174,20 +426,92 @@ impl Meta {
     /// If you are an end user of Cosmic Time, you do not want this.
     /// You want the `pause` function on [`Timeline`].
     pub fn pause(&mut self, now: Instant) {
-        if let Pause::Resumed(delay) = self.pause {
-            self.pause = Pause::Paused(relative_time(&now.checked_sub(delay).unwrap(), self));
-        } else {
-            self.pause = Pause::Paused(relative_time(&now, self));
-        }
+        self.pause = Pause::Paused(self.played(now));
     }
 
     /// Sets the animation to be resumed.
     /// If you are an end user of Cosmic Time, you do not want this.
     /// You want the `resume` function on [`Timeline`].
     pub fn resume(&mut self, now: Instant) {
-        if let Pause::Paused(start) = self.pause {
-            self.pause = Pause::Resumed(now - start);
+        // Everything but the time played has been spent paused, including
+        // earlier pauses, so each resume continues where the last pause was.
+        if let Pause::Paused(played) = self.pause {
+            self.pause = Pause::Resumed(self.scaled(now).saturating_duration_since(played));
+        }
+    }
+
+    /// Sets how fast the animation plays from `now` on.
+    /// If you are an end user of Cosmic Time, you do not want this.
+    /// You want the `set_rate` function on [`Timeline`].
+    pub fn set_rate(&mut self, now: Instant, rate: f32) {
+        self.rate = Some(Rate {
+            rate,
+            real: now,
+            scaled: self.scaled(now),
+        });
+    }
+
+    // The instant `now` is for the animation, at its playback rate.
+    fn scaled(&self, now: Instant) -> Instant {
+        let Some(Rate { rate, real, scaled }) = self.rate else {
+            return now;
+        };
+        let (is_forward, elapsed) = if now >= real {
+            (rate >= 0.0, now - real)
+        } else {
+            (rate < 0.0, real - now)
+        };
+        let elapsed = elapsed.mul_f32(rate.abs());
+        if is_forward {
+            scaled + elapsed
+        } else {
+            scaled.checked_sub(elapsed).unwrap_or(scaled)
+        }
+    }
+
+    // How far the animation has played at `now`, as an instant from its
+    // start: at its playback rate, without the time spent paused, and with
+    // every loop counted.
+    fn played(&self, now: Instant) -> Instant {
+        match self.pause {
+            Pause::NoPause => self.scaled(now),
+            Pause::Resumed(paused) => self.scaled(now).checked_sub(paused).unwrap_or(self.start),
+            Pause::Paused(played) => played,
+        }
+    }
+
+    // The instant into the first loop of the animation that is shown at `now`.
+    fn relative_now(&self, now: Instant) -> Instant {
+        relative_time(&self.played(now), self)
+    }
+
+    // Playing, and either time is left or it loops forever.
+    // A rate of zero holds still, and a negative rate plays back to the start.
+    fn is_animating(&self, now: Instant) -> bool {
+        let rate = self.rate.map_or(1.0, |rate| rate.rate);
+        let has_time_left = if rate < 0.0 {
+            self.played(now) > self.start
+        } else {
+            self.end >= self.played(now)
+        };
+        self.pause.is_playing() && rate != 0.0 && (self.loops_forever() || has_time_left)
+    }
+
+    // Real time left until the end, or `None` if the animation never ends.
+    // Paused animations don't count down, and the playback rate is honored.
+    fn remaining(&self, now: Instant) -> Option<Duration> {
+        let rate = self.rate.map_or(1.0, |rate| rate.rate);
+        if self.loops_forever() || rate <= 0.0 {
+            return None;
         }
+        let remaining = self.end.saturating_duration_since(self.played(now));
+        // Only scale with a rate set, so normal speed stays exact.
+        Some(self.rate.map_or(remaining, |_| remaining.div_f32(rate)))
+    }
+
+    // Zero-length loops hold their last keyframe instead.
+    fn loops_forever(&self) -> bool {
+        matches!(self.repeat, Repeat::Forever | Repeat::PingPong) && !self.length.is_zero()
     }
 }
 
@@ -195,12 +519,13 @@ impl Meta {
 /// to be resumed and/or continue animating.
 #[derive(Debug, Clone, Copy)]
 pub enum Pause {
-    /// Currently paused, with the relative instant into the animation it was paused at.
+    /// Currently paused, with how far the animation had played when paused,
+    /// as an instant from its start, counting every loop.
     Paused(Instant),
     /// Has never been paused
     NoPause,
-    /// The animation was paused, but no longer. The duration is required for the
-    /// offset of the animation.
+    /// The animation was paused, but no longer. The duration is the total time
+    /// spent paused, over every pause, required for the offset of the animation.
     Resumed(Duration),
 }
 
@@ -275,15 +600,63 @@ pub struct Interped {
     pub percent: f32,
 }
 
+/// Why [`Timeline::try_get`] has no value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GetError {
+    /// There is no animation with this Id.
+    NoAnimation,
+    /// The animation exists, but has no modifier at this index.
+    OutOfRange {
+        /// The index that was read.
+        index: usize,
+        /// The number of modifiers the animation has.
+        len: usize,
+    },
+    /// The modifier at this index has no keyframes.
+    NoFrames,
+}
+
+impl std::fmt::Display for GetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetError::NoAnimation => write!(f, "no animation with this id"),
+            GetError::OutOfRange { index, len } => write!(
+                f,
+                "index {index} is out of range for an animation with {len} modifiers"
+            ),
+            GetError::NoFrames => write!(f, "no keyframes for this modifier"),
+        }
+    }
+}
+
+impl std::error::Error for GetError {}
+
 impl Timeline {
     /// Creates a new [`Timeline`]. If you don't find this function you are going
     /// to have a bad time.
     #[must_use]
     pub fn new() -> Self {
         Timeline {
-            tracks: HashMap::new(),
-            pendings: HashMap::new(),
+            tracks: FnvHashMap::default(),
+            pendings: FnvHashMap::default(),
             now: None,
+            prev_now: None,
+            quantize: Duration::ZERO,
+            reduced_motion: None,
+            clock: None,
+            warned: Warned::default(),
+        }
+    }
+
+    /// Creates a new [`Timeline`] that reads the time from `clock` instead of
+    /// the system clock, wherever a time isn't given, like in
+    /// [`Timeline::start`] or before the first [`Timeline::now`]. This makes
+    /// tests reproducible, with no sleeping.
+    #[must_use]
+    pub fn with_clock(clock: impl Fn() -> Instant + Send + Sync + 'static) -> Self {
+        Timeline {
+            clock: Some(Clock(Arc::new(clock))),
+            ..Timeline::new()
         }
     }
 
@@ -293,11 +666,103 @@ impl Timeline {
         self.pendings.clear();
     }
 
+    /// Like [`Timeline::remove_pending`], but only for one animation.
+    /// Returns `true` if that animation had anything pending.
+    pub fn prune_pending(&mut self, id: impl Into<widget::Id>) -> bool {
+        self.pendings.remove(&id.into()).is_some()
+    }
+
+    /// Re-key every animation, e.g. after cloning a timeline that was set up
+    /// for a component template. Both started and pending animations are
+    /// moved. Pendings for all animations, like [`Timeline::pause_all`], stay as they are.
+    pub fn map_ids(&mut self, f: impl Fn(&widget::Id) -> widget::Id) {
+        self.tracks = std::mem::take(&mut self.tracks)
+            .into_iter()
+            .map(|(id, track)| (f(&id), track))
+            .collect();
+        self.pendings = std::mem::take(&mut self.pendings)
+            .into_iter()
+            .map(|(id, pending)| match pending {
+                Pending::PauseAll | Pending::ResumeAll | Pending::ReverseAll => (id, pending),
+                _ => (f(&id), pending),
+            })
+            .collect();
+    }
+
     fn get_now(&self) -> Instant {
         match self.now {
             Some(now) => now,
-            None => Instant::now(),
+            None => {
+                // Tracks set with `set_track` can be read before the first tick.
+                // A user clock is deliberate, so only the system clock warns.
+                if cfg!(debug_assertions)
+                    && self.clock.is_none()
+                    && !self.warned.0.swap(true, atomic::Ordering::Relaxed)
+                {
+                    eprintln!(
+                        "cosmic-time: the timeline was read before `Timeline::now` was called, \
+                         falling back to the current time"
+                    );
+                }
+                self.clock()
+            }
+        }
+    }
+
+    // The current time, from the clock set with `with_clock` if any.
+    fn clock(&self) -> Instant {
+        self.clock
+            .as_ref()
+            .map_or_else(Instant::now, |clock| (clock.0)())
+    }
+
+    /// Round animation time down to a multiple of `step`, measured from the start
+    /// of each animation. Pair this with a capped subscription (say 30 FPS) to
+    /// keep the animation math consistent with the redraws.
+    /// `Duration::ZERO` turns quantization off, which is the default.
+    pub fn quantize(&mut self, step: Duration) -> &mut Self {
+        self.quantize = step;
+        self
+    }
+
+    /// Honor the user's preference for reduced motion. While enabled, every
+    /// animation reads as its last keyframe, so widgets like the toggler and
+    /// cards snap to their new state. Looping and paused animations hold their
+    /// last keyframe too, and delays are skipped. Animations that were running
+    /// stop at the next [`Timeline::now`], so [`Timeline::completed`] reports
+    /// them and [`Timeline::as_subscription`] requests just that one frame.
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) -> &mut Self {
+        self.reduced_motion = match (reduced_motion, self.reduced_motion) {
+            (false, _) => None,
+            (true, Some(since)) => Some(since),
+            (true, None) => Some(self.now.unwrap_or_else(|| self.clock())),
+        };
+        self
+    }
+
+    /// Is reduced motion enabled? See [`Timeline::set_reduced_motion`].
+    #[must_use]
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion.is_some()
+    }
+
+    // Whether a track is still moving at `now`, see `Meta::is_animating`.
+    // With reduced motion, tracks stop after the last frame before it was
+    // enabled, or after their first frame if started since.
+    fn is_moving(&self, meta: &Meta, now: Instant) -> bool {
+        meta.is_animating(now)
+            && self
+                .reduced_motion
+                .is_none_or(|since| now <= since.max(meta.start))
+    }
+
+    fn quantized(&self, now: Instant, meta: &Meta) -> Instant {
+        if self.quantize.is_zero() || now <= meta.start {
+            return now;
         }
+        let elapsed = (now - meta.start).as_nanos();
+        let step = self.quantize.as_nanos();
+        meta.start + Duration::from_nanos((elapsed - elapsed % step) as u64)
     }
 
     /// Need to pause an animation? Use this! Pass the same widget Id
@@ -332,6 +797,24 @@ impl Timeline {
         self
     }
 
+    /// Play an animation backward, from wherever it currently is back to
+    /// its start. Takes effect on the next [`Timeline::start`].
+    /// Eases are reused as they are, so the way back is not an exact mirror
+    /// of asymmetric eases. A looping animation only plays back once.
+    pub fn reverse(&mut self, id: impl Into<widget::Id>) -> &mut Self {
+        let id = id.into();
+        let _ = self.pendings.insert(id, Pending::Reverse);
+        self
+    }
+
+    /// Play all animations backward. See [`Timeline::reverse`].
+    pub fn reverse_all(&mut self) -> &mut Self {
+        let _ = self
+            .pendings
+            .insert(widget::Id::unique(), Pending::ReverseAll);
+        self
+    }
+
     /// Add an animation chain to the timeline!
     /// Each animation Id is unique. It is imposible to use the same Id
     /// for two animations.
@@ -341,7 +824,8 @@ impl Timeline {
 
     /// Like `set_chain` but the animation will start paused on it's first frame.
     pub fn set_chain_paused(&mut self, chain: impl Into<Chain>) -> &mut Self {
-        self.set_chain_with_options(chain, Pause::Paused(Instant::now()))
+        let now = self.clock();
+        self.set_chain_with_options(chain, Pause::Paused(now))
     }
 
     fn set_chain_with_options(&mut self, chain: impl Into<Chain>, pause: Pause) -> &mut Self {
@@ -353,7 +837,24 @@ impl Timeline {
 
         let _ = self
             .pendings
-            .insert(id, Pending::Chain(repeat, chain.links, pause));
+            .insert(id, Pending::Chain(repeat, chain.links, pause, chain.delay));
+        self
+    }
+
+    /// The low-level counterpart to [`Timeline::set_chain`], for tooling and
+    /// replaying recorded animations. Inserts an already started track,
+    /// replacing any animation with the same Id.
+    /// Nothing is resolved or checked, so the caller is responsible for
+    /// correct timing: `subframes[i]` holds the keyframes of the animation's
+    /// `i`th value, sorted by [`SubFrame::at`], and `meta` matches them.
+    pub fn set_track(
+        &mut self,
+        id: impl Into<widget::Id>,
+        meta: Meta,
+        subframes: Vec<Vec<SubFrame>>,
+    ) -> &mut Self {
+        let track = subframes.into_iter().map(SmallVec::from_vec).collect();
+        let _ = self.tracks.insert(id.into(), (meta, track));
         self
     }
 
@@ -365,23 +866,197 @@ impl Timeline {
         self
     }
 
+    /// Moves every animation from `other` into this timeline, both started and
+    /// pending, so one subscription and one [`Timeline::now`] drive them all.
+    /// If both timelines have an animation with the same Id, `other`'s
+    /// replaces this one's, like [`Timeline::set_chain`]. This timeline's
+    /// time and settings are kept. Pendings for all animations from `other`,
+    /// like [`Timeline::pause_all`], apply to the whole merged timeline.
+    pub fn extend(&mut self, other: Timeline) -> &mut Self {
+        self.tracks.extend(other.tracks);
+        self.pendings.extend(other.pendings);
+        self
+    }
+
+    /// Removes every animation, started or pending, and forgets the time, like
+    /// a new timeline. Settings like [`Timeline::set_reduced_motion`] are kept.
+    /// Useful when tearing down a screen. [`Timeline::as_subscription`] is
+    /// [`Subscription::none`] until new chains are set and started.
+    pub fn clear_all(&mut self) -> &mut Self {
+        self.tracks.clear();
+        self.pendings.clear();
+        self.now = None;
+        self.prev_now = None;
+        self
+    }
+
+    /// How many animations, and pauses, resumes or reverses, are waiting for
+    /// [`Timeline::start`].
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.pendings.len()
+    }
+
+    /// How many started animations are still running, by the same criteria
+    /// as [`Timeline::is_animating`].
+    #[must_use]
+    pub fn active_count(&self) -> usize {
+        self.now.map_or(0, |now| {
+            self.tracks
+                .values()
+                .filter(|(meta, _track)| self.is_moving(meta, now))
+                .count()
+        })
+    }
+
+    /// The Ids of the started animations that are still running, by the
+    /// same criteria as [`Timeline::is_animating`], in no particular order.
+    /// Useful for debug overlays.
+    pub fn active_ids(&self) -> impl Iterator<Item = &widget::Id> {
+        let now = self.now;
+        self.tracks
+            .iter()
+            .filter(move |(_id, (meta, _track))| now.is_some_and(|now| self.is_moving(meta, now)))
+            .map(|(id, _)| id)
+    }
+
+    /// The Ids of every started animation, running or finished, in no
+    /// particular order. Pending animations aren't included.
+    pub fn all_ids(&self) -> impl Iterator<Item = &widget::Id> {
+        self.tracks.keys()
+    }
+
     /// Use this in your `update()`.
     /// Updates the timeline's time so that animations can continue atomically.
+    /// Until this (or [`Timeline::start`]) has been called, [`Timeline::get`]
+    /// falls back to `Instant::now()`, which is not consistent within a frame,
+    /// or to the clock of [`Timeline::with_clock`]. Without a clock, debug
+    /// builds print a warning the first time that happens.
     pub fn now(&mut self, now: Instant) {
+        if self.now != Some(now) {
+            self.prev_now = self.now;
+        }
         self.now = Some(now);
     }
 
+    /// Like [`Timeline::now`], but returns whether any animation moved since
+    /// the previous call, so a redraw can be skipped while everything is idle.
+    /// An animation that reaches its end on this tick still counts as moved,
+    /// so the last frame is drawn. Nothing moves if `now` didn't change, and
+    /// with [`Timeline::set_reduced_motion`] animations only move once, to
+    /// their end.
+    pub fn now_changed(&mut self, now: Instant) -> bool {
+        let prev = self.now;
+        self.now(now);
+        match prev {
+            Some(prev) if prev == now => false,
+            Some(prev) => self
+                .tracks
+                .values()
+                .any(|(meta, _track)| self.is_moving(meta, prev)),
+            None => !self.is_idle(),
+        }
+    }
+
+    /// Jumps the timeline's clock to `now`, for scrubbing through animations.
+    /// Unlike [`Timeline::now`], this may move backward, and every animation
+    /// is shown as it was (or will be) at that instant. Because a seek isn't
+    /// motion, [`Timeline::get_prev`] matches the value until the next tick.
+    ///
+    /// Paused animations stay where they were paused, and resumed ones stay
+    /// behind by however long they were paused. Pending changes like
+    /// [`Timeline::pause`] still wait for [`Timeline::start`], and seeking
+    /// back before an animation ends makes [`Timeline::as_subscription`]
+    /// request frames again.
+    pub fn seek(&mut self, now: Instant) {
+        self.prev_now = None;
+        self.now = Some(now);
+    }
+
+    /// The instant set by the last call to [`Timeline::now`] or [`Timeline::start`].
+    /// `None` if the timeline has never been ticked.
+    #[must_use]
+    pub fn now_instant(&self) -> Option<Instant> {
+        self.now
+    }
+
+    /// Sets every chain, then starts them all exactly once so they stay in sync.
+    /// See the [`crate::chains!`] macro to mix chains of different keyframe types.
+    pub fn set_and_start(&mut self, chains: impl IntoIterator<Item = impl Into<Chain>>) {
+        self.set_and_start_at(chains, self.clock());
+    }
+
+    /// Like [`Timeline::set_and_start`], but starts the chains at some other time that isn't now.
+    pub fn set_and_start_at(
+        &mut self,
+        chains: impl IntoIterator<Item = impl Into<Chain>>,
+        now: Instant,
+    ) {
+        for chain in chains {
+            let _ = self.set_chain(chain);
+        }
+        self.start_at(now);
+    }
+
+    /// Sets every chain, each starting `stagger` after the one before it,
+    /// then starts them all. See [`Chain::stagger`].
+    pub fn set_chains_staggered(
+        &mut self,
+        chains: impl IntoIterator<Item = impl Into<Chain>>,
+        stagger: Duration,
+    ) {
+        self.set_chains_staggered_at(chains, stagger, self.clock());
+    }
+
+    /// Like [`Timeline::set_chains_staggered`], but starts the chains at some
+    /// other time that isn't now.
+    pub fn set_chains_staggered_at(
+        &mut self,
+        chains: impl IntoIterator<Item = impl Into<Chain>>,
+        stagger: Duration,
+        now: Instant,
+    ) {
+        self.set_and_start_at(Chain::stagger(chains, stagger), now);
+    }
+
     /// Starts all pending animations.
     pub fn start(&mut self) {
-        self.start_at(Instant::now());
+        self.start_at(self.clock());
     }
 
     /// Starts all pending animations at some other time that isn't now.
     pub fn start_at(&mut self, now: Instant) {
+        self.start_pendings(now, |_id, _meta| {});
+    }
+
+    /// Like [`Timeline::start_at`], but returns the instant each newly started
+    /// animation will end at. Useful to schedule work for when an animation is done.
+    pub fn start_at_returning(&mut self, now: Instant) -> HashMap<widget::Id, Instant> {
+        let mut ends = HashMap::new();
+        self.start_pendings(now, |id, meta| {
+            let _ = ends.insert(id.clone(), meta.end);
+        });
+        ends
+    }
+
+    fn start_pendings(&mut self, now: Instant, mut on_start: impl FnMut(&widget::Id, &Meta)) {
+        // Apps often start every update, usually with nothing to start.
+        if self.pendings.is_empty() {
+            self.now(now);
+            return;
+        }
+        // Taken so `self` can be used while draining, then put back to keep its capacity.
         let mut pendings = std::mem::take(&mut self.pendings);
         for (id, pending) in pendings.drain() {
             match pending {
-                Pending::Chain(repeat, chain, pause) => {
+                Pending::Chain(repeat, mut chain, pause, delay) => {
+                    // Delayed chains start later, holding their first keyframe until then.
+                    // Reduced motion shows the last keyframe right away instead.
+                    let (now, repeat) = if self.reduced_motion() {
+                        (now, Repeat::Never)
+                    } else {
+                        (now + delay, repeat)
+                    };
                     let mut end = now;
                     // The time that the chain was `set_chain_paused` is not
                     // necessaritly the same as the atomic pause time used here.
@@ -393,56 +1068,54 @@ impl Timeline {
                     };
 
                     let cols = chain[0].len();
-                    let rows = chain.len();
+                    // Resolve every frame to a value first. Relative frames
+                    // add to the value before them in the chain, or to the
+                    // current value if they come first, like lazy frames.
+                    let mut previous: SmallVec<[Option<f32>; 2]> = smallvec![None; cols];
+                    for row in &mut chain {
+                        for (i, frame) in row.iter_mut().enumerate() {
+                            let Some(frame) = frame else { continue };
+                            if let (Frame::Relative(at, delta, ease), Some(value)) =
+                                (*frame, previous[i])
+                            {
+                                *frame = Frame::Eager(at, value + delta, ease);
+                            }
+                            frame.to_eager(self, &id, i);
+                            previous[i] = Some(frame.get_value());
+                        }
+                    }
+
+                    let mut track: Track = smallvec![SubFrames::new(); cols];
                     let mut peekable = chain.into_iter().peekable();
-                    let mut specific_chain = Vec::with_capacity(rows);
                     while let Some(current) = peekable.next() {
                         let time = end;
                         if let Some(next) = peekable.peek() {
-                            let mut counter = 0;
-                            if let Some((c_frame, n_frame)) =
-                                current.iter().zip(next.iter()).find(|(c_frame, n_frame)| {
-                                    counter += 1;
-                                    c_frame.is_some() && n_frame.is_some()
-                                })
+                            if let Some((Some(c), Some(n))) = current
+                                .iter()
+                                .zip(next.iter())
+                                .find(|(c_frame, n_frame)| c_frame.is_some() && n_frame.is_some())
                             {
-                                let mut c = c_frame.expect("Previous check guarentees saftey");
-                                let mut n = n_frame.expect("Previous check guarentees saftey");
-                                c.to_eager(self, &id, counter - 1);
-                                n.to_eager(self, &id, counter - 1);
-                                let duration = n.get_duration(&c);
-                                end += duration;
+                                if !self.reduced_motion() {
+                                    end += n.get_duration(c);
+                                }
                             }
                         }
 
-                        let specific_row = current.into_iter().enumerate().fold(
-                            Vec::with_capacity(cols),
-                            |mut acc, (i, maybe_frame)| {
-                                if let Some(mut frame) = maybe_frame {
-                                    frame.to_eager(self, &id, i);
-                                    acc.push(Some(frame.to_subframe(time)))
-                                } else {
-                                    acc.push(None)
-                                }
-                                acc
-                            },
-                        );
-                        specific_chain.push(specific_row);
+                        // Each value's keyframes go to its own column of the track.
+                        for (i, maybe_frame) in current.into_iter().enumerate() {
+                            if let Some(frame) = maybe_frame {
+                                track[i].push(frame.to_subframe(time));
+                            }
+                        }
                     }
-                    let transposed = specific_chain.into_iter().fold(
-                        vec![Vec::new(); cols],
-                        |mut acc: Vec<Vec<SubFrame>>, row| {
-                            row.into_iter().enumerate().for_each(|(j, maybe_item)| {
-                                if let Some(item) = maybe_item {
-                                    acc[j].push(item)
-                                }
-                            });
-                            acc
-                        },
-                    );
 
-                    let meta = Meta::new(repeat, now, end, end - now, pause);
-                    let _ = self.tracks.insert(id, (meta, transposed));
+                    let length = end - now;
+                    if let Repeat::Times(times) = repeat {
+                        end = now + length * times.max(1);
+                    }
+                    let meta = Meta::new(repeat, now, end, length, pause);
+                    on_start(&id, &meta);
+                    let _ = self.tracks.insert(id, (meta, track));
                 }
                 Pending::Pause => {
                     if let Some((meta, _track)) = self.tracks.get_mut(&id) {
@@ -464,112 +1137,283 @@ impl Timeline {
                         meta.resume(now);
                     }
                 }
+                Pending::Reverse => {
+                    if let Some((meta, track)) = self.tracks.get_mut(&id) {
+                        reverse_track(meta, track, now);
+                    }
+                }
+                Pending::ReverseAll => {
+                    for (meta, track) in self.tracks.values_mut() {
+                        reverse_track(meta, track, now);
+                    }
+                }
             }
         }
+        self.pendings = pendings;
         self.now(now);
     }
 
+    /// Scales how fast an animation plays from now on. `1.0` is normal
+    /// speed, `0.5` is half speed and `2.0` is double. `0.0` holds the
+    /// animation still without pausing it, and negative rates play it
+    /// backward, stopping at its start. Applies immediately, and lasts until
+    /// the animation's chain is replaced.
+    pub fn set_rate(&mut self, id: impl Into<widget::Id>, rate: f32) -> &mut Self {
+        let now = self.get_now();
+        if let Some((meta, _track)) = self.tracks.get_mut(&id.into()) {
+            meta.set_rate(now, rate);
+        }
+        self
+    }
+
     /// Get the [`Interped`] value for an animation.
     /// Use internaly by Cosmic Time.
     /// index is the index that the keyframe arbitratily assigns to each
     /// widget modifier (think width/height).
+    /// See [`Timeline::try_get`] to tell why there is no value.
     #[must_use]
     pub fn get(&self, id: &widget::Id, index: usize) -> Option<Interped> {
-        let now = self.get_now();
-        // Get requested modifier_timeline or skip
-        let (meta, mut modifier_timeline) = if let Some((meta, chain)) = self.tracks.get(id) {
-            if let Some(modifier_timeline) = chain.get(index) {
-                (meta, modifier_timeline.iter())
-            } else {
-                return None;
-            }
-        } else {
-            return None;
-        };
-
-        let relative_now = match meta.pause {
-            Pause::NoPause => relative_time(&now, meta),
-            Pause::Resumed(delay) => relative_time(&now.checked_sub(delay).unwrap(), meta),
-            Pause::Paused(time) => relative_time(&time, meta),
-        };
+        self.try_get(id, index).ok()
+    }
 
-        // Loop through modifier_timeline, returning the interpolated value if possible.
-        let mut accumulator: Option<&SubFrame> = None;
-        loop {
-            match (accumulator, modifier_timeline.next()) {
-                // Found first element in timeline
-                (None, Some(modifier)) => accumulator = Some(modifier),
-                // No Elements in timeline
-                (None, None) => return None,
-                // Accumulator found in previous loop, but no greater value. Means animation duration has expired.
-                (Some(acc), None) => {
-                    return Some(Interped {
-                        previous: acc.value,
-                        next: acc.value,
-                        percent: 1.0,
-                        value: acc.value,
-                    });
-                }
-                // Found accumulator in middle-ish of timeline
-                (Some(acc), Some(modifier)) => {
-                    // Can not interpolate between this one and next value?
-                    if relative_now >= modifier.at || acc.value == modifier.value {
-                        accumulator = Some(modifier);
-                    // Can interpolate between these two, thus calculate and return that value.
-                    } else {
-                        let elapsed = relative_now.duration_since(acc.at).as_millis() as f32;
-                        let duration = (modifier.at - acc.at).as_millis() as f32;
-
-                        let previous = acc.value;
-                        let next = modifier.value;
-                        let percent = modifier.ease.tween(elapsed / duration);
-                        let value = lerp(
-                            acc.value,
-                            modifier.value,
-                            modifier.ease.tween(elapsed / duration),
-                        );
+    /// Like [`Timeline::get`], but tells an unknown Id apart from an index
+    /// that the animation doesn't have, like after reading with the wrong
+    /// keyframe type's index.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GetError`] describing why there is no value.
+    pub fn try_get(&self, id: &widget::Id, index: usize) -> Result<Interped, GetError> {
+        let (meta, chain) = self.tracks.get(id).ok_or(GetError::NoAnimation)?;
+        let modifier_timeline = chain.get(index).ok_or(GetError::OutOfRange {
+            index,
+            len: chain.len(),
+        })?;
 
-                        return Some(Interped {
-                            previous,
-                            next,
-                            value,
-                            percent,
-                        });
-                    }
-                }
-            }
+        if self.reduced_motion() {
+            return settled(modifier_timeline).ok_or(GetError::NoFrames);
         }
+        let now = self.quantized(self.get_now(), meta);
+        interp(modifier_timeline, meta.relative_now(now)).ok_or(GetError::NoFrames)
     }
 
-    /// Check if the timeline is idle
-    /// The timeline is considered idle if all animations meet
-    /// one of the final criteria:
-    /// 1. Played until completion
-    /// 2. Paused
-    /// 3. Does not loop forever
+    /// The value an animation had at the previous [`Timeline::now`], to
+    /// compute deltas between frames. Same as the value from
+    /// [`Timeline::get`] until `now` has changed once.
     #[must_use]
-    pub fn is_idle(&self) -> bool {
-        let now = self.now;
-        !(now.is_some()
-            && self.tracks.values().any(|track| {
-                (track.0.repeat == Repeat::Forever && track.0.pause.is_playing())
-                    || (track.0.end >= now.unwrap() && track.0.pause.is_playing())
-            }))
+    pub fn get_prev(&self, id: &widget::Id, index: usize) -> Option<f32> {
+        let (meta, chain) = self.tracks.get(id)?;
+        let modifier_timeline = chain.get(index)?;
+        if self.reduced_motion() {
+            return settled(modifier_timeline).map(|interped| interped.value);
+        }
+        let prev_now = self.quantized(self.prev_now.unwrap_or_else(|| self.get_now()), meta);
+        interp(modifier_timeline, meta.relative_now(prev_now)).map(|interped| interped.value)
     }
 
-    // /// Efficiently request redraws for animations.
-    // /// Automatically checks if animations are in a state where redraws arn't necessary.
-    // #[cfg(not(feature = "libcosmic"))]
-    // pub fn as_subscription<Event>(&self) -> Subscription<Instant> {
-    //     if self.is_idle() {
-    //         Subscription::none()
-    //     } else {
-    //         iced::window::frames()
-    //     }
-    // }
+    /// Get the current value of a two-state animation, between `0.0` ("off")
+    /// and `1.0` ("on"). See [`crate::chain::TwoState`].
+    #[must_use]
+    pub fn two_state(&self, id: &widget::Id) -> Option<f32> {
+        self.get(id, 0).map(|interped| interped.value)
+    }
+
+    /// Get the current value of a plain number animation, that isn't tied to
+    /// a widget. See [`crate::chain::Value`].
+    #[must_use]
+    pub fn value(&self, id: &widget::Id) -> Option<f32> {
+        self.get(id, 0).map(|interped| interped.value)
+    }
+
+    /// How far along an animation is, from `0.0` at its start to `1.0` at its
+    /// end, ignoring eases. Pauses are honored, and looping animations report
+    /// how far into the current loop they are. With
+    /// [`Timeline::set_reduced_motion`], every animation is at its end.
+    pub fn progress(&self, id: &widget::Id) -> Option<f32> {
+        let (meta, _track) = self.tracks.get(id)?;
+        if self.reduced_motion() || meta.length.is_zero() {
+            return Some(1.0);
+        }
+        let relative_now = meta.relative_now(self.quantized(self.get_now(), meta));
+        let elapsed = relative_now.saturating_duration_since(meta.start);
+        Some((elapsed.as_secs_f64() / meta.length.as_secs_f64()).min(1.0) as f32)
+    }
+
+    /// How much real time is left until an animation ends. Time spent paused
+    /// doesn't count, and a finished animation has [`Duration::ZERO`] left.
+    /// `None` if there is no animation, or if it never ends, like when it
+    /// loops forever or plays backward. With [`Timeline::set_reduced_motion`],
+    /// every animation is already finished.
+    #[must_use]
+    pub fn remaining(&self, id: &widget::Id) -> Option<Duration> {
+        let (meta, _track) = self.tracks.get(id)?;
+        if self.reduced_motion() {
+            return Some(Duration::ZERO);
+        }
+        meta.remaining(self.get_now())
+    }
+
+    /// Get an x/y pair of an animation as a [`Vector`], with index `0` as x
+    /// and index `1` as y. Both are read from the same track, so they are
+    /// always in sync. See [`crate::chain::Offset`].
+    #[must_use]
+    pub fn get_vector(&self, id: &widget::Id) -> Option<Vector> {
+        let (meta, track) = self.tracks.get(id)?;
+        if self.reduced_motion() {
+            return Some(Vector::new(
+                settled(track.first()?)?.value,
+                settled(track.get(1)?)?.value,
+            ));
+        }
+        let relative_now = meta.relative_now(self.quantized(self.get_now(), meta));
+        Some(Vector::new(
+            interp(track.first()?, relative_now)?.value,
+            interp(track.get(1)?, relative_now)?.value,
+        ))
+    }
+
+    /// The point `t` of the way along the path through an x/y animation's
+    /// keyframes, like [`crate::chain::Path`], measured by distance.
+    /// `t` is clamped to `[0, 1]`. Time and eases are ignored, so `t` can be
+    /// driven by anything, such as a scrubber or another animation.
+    pub fn get_along_path(&self, id: &widget::Id, t: f32) -> Option<Point> {
+        let (_meta, track) = self.tracks.get(id)?;
+        let (xs, ys) = (track.first()?, track.get(1)?);
+        let points = || xs.iter().zip(ys).map(|(x, y)| Point::new(x.value, y.value));
+        let length: f32 = points()
+            .zip(points().skip(1))
+            .map(|(a, b)| a.distance(b))
+            .sum();
+        let mut remaining = length * t.clamp(0.0, 1.0);
+        let mut last = points().next()?;
+        for (a, b) in points().zip(points().skip(1)) {
+            let segment = a.distance(b);
+            if remaining < segment {
+                let p = remaining / segment;
+                return Some(Point::new(lerp(a.x, b.x, p), lerp(a.y, b.y, p)));
+            }
+            remaining -= segment;
+            last = b;
+        }
+        Some(last)
+    }
+
+    /// The instant of the keyframe closest to `at`, out of all of an
+    /// animation's modifiers. Useful to snap a scrub handle to keyframes.
+    /// For a looping animation these are the instants of the first loop.
+    #[must_use]
+    pub fn nearest_keyframe(&self, id: &widget::Id, at: Instant) -> Option<Instant> {
+        let (_meta, track) = self.tracks.get(id)?;
+        track
+            .iter()
+            .flatten()
+            .map(|subframe| subframe.at)
+            .min_by_key(|keyframe| {
+                if *keyframe > at {
+                    *keyframe - at
+                } else {
+                    at - *keyframe
+                }
+            })
+    }
+
+    /// Get the current index of a frame index animation, rounded down so each
+    /// index is held until the next. See [`crate::chain::FrameIndex`].
+    #[must_use]
+    pub fn get_index(&self, id: &widget::Id) -> Option<usize> {
+        self.get(id, 0).map(|interped| {
+            // Interpolation can land a few ulps short of a whole index, so values
+            // that close to one are rounded to it. The error grows with the
+            // values interpolated between, and so does the tolerance.
+            let scale = interped.previous.abs().max(interped.next.abs()).max(1.0);
+            let nearest = interped.value.round();
+            let index = if (interped.value - nearest).abs() <= 4.0 * f32::EPSILON * scale {
+                nearest
+            } else {
+                interped.value.floor()
+            };
+            index.max(0.0) as usize
+        })
+    }
+
+    /// Get the largest size out of several animations, each given as
+    /// `(id, width_index, height_index)`. Width and height are maxed
+    /// separately, so a parent can size itself to fit every animating child.
+    /// Ids without an animation are skipped, returns `None` if none have one.
+    #[must_use]
+    pub fn bounds(&self, ids: &[(widget::Id, usize, usize)]) -> Option<Size> {
+        ids.iter()
+            .filter_map(|(id, width, height)| {
+                Some(Size::new(
+                    self.get(id, *width)?.value,
+                    self.get(id, *height)?.value,
+                ))
+            })
+            .reduce(|acc, size| acc.max(size))
+    }
+
+    /// Check if the timeline is idle
+    /// The timeline is considered idle if all animations meet
+    /// one of the final criteria:
+    /// 1. Played until completion
+    /// 2. Paused
+    /// 3. Does not loop forever
+    #[must_use]
+    pub fn is_idle(&self) -> bool {
+        !self.now.is_some_and(|now| {
+            self.tracks
+                .values()
+                .any(|(meta, _track)| self.is_moving(meta, now))
+        })
+    }
+
+    /// Is this animation still running? That is, it is playing and either
+    /// has time left or loops forever. Uses the same criteria as
+    /// [`Timeline::is_idle`], so it is `false` until the timeline has started.
+    #[must_use]
+    pub fn is_animating(&self, id: &widget::Id) -> bool {
+        self.now.is_some_and(|now| {
+            self.tracks
+                .get(id)
+                .is_some_and(|(meta, _track)| self.is_moving(meta, now))
+        })
+    }
+
+    /// The animations that finished on this frame, between the previous and
+    /// the current [`Timeline::now`], by the same criteria as
+    /// [`Timeline::is_animating`]. Each is reported on exactly one frame,
+    /// so in `update()` this can be mapped to the app's own messages, like
+    /// moving to the next screen once a transition is done. Looping
+    /// animations only finish with [`Timeline::set_reduced_motion`], a chain
+    /// that is replaced before its end doesn't finish, and nothing finishes
+    /// on a [`Timeline::seek`].
+    pub fn completed(&self) -> impl Iterator<Item = &widget::Id> {
+        let frame = self.prev_now.zip(self.now);
+        self.tracks
+            .iter()
+            .filter(move |(_id, (meta, _track))| {
+                frame.is_some_and(|(prev_now, now)| {
+                    self.is_moving(meta, prev_now) && !self.is_moving(meta, now)
+                })
+            })
+            .map(|(id, _)| id)
+    }
+
+    // /// Efficiently request redraws for animations.
+    // /// Automatically checks if animations are in a state where redraws arn't necessary.
+    // #[cfg(not(feature = "libcosmic"))]
+    // pub fn as_subscription<Event>(&self) -> Subscription<Instant> {
+    //     if self.is_idle() {
+    //         Subscription::none()
+    //     } else {
+    //         iced::window::frames()
+    //     }
+    // }
 
     /// Efficiently request redraws for animations.
     /// Automatically checks if animations are in a state where redraws arn't necessary.
+    /// When idle this is [`Subscription::none`], so it can be batched with the
+    /// app's own subscriptions without keeping the app awake.
     pub fn as_subscription(&self) -> Subscription<(cosmic::iced::window::Id, Instant)> {
         if self.is_idle() {
             Subscription::none()
@@ -577,20 +1421,1639 @@ impl Timeline {
             cosmic::iced_runtime::window::frames() // ~120FPS
         }
     }
+
+    /// Like [`Timeline::as_subscription`], but ticks at most `fps` times a
+    /// second, rather than on every frame of the display, to save power on
+    /// high refresh rate displays. Ticks come from a timer, so they only carry
+    /// the [`Instant`] to pass to [`Timeline::now`]. Pair it with
+    /// [`Timeline::quantize`] at the same rate to keep the animation steps
+    /// even. An `fps` of 0 is treated as 1.
+    pub fn as_subscription_capped(&self, fps: u32) -> Subscription<Instant> {
+        if self.is_idle() {
+            Subscription::none()
+        } else {
+            cosmic::iced::time::every(frame_interval(fps))
+        }
+    }
+}
+
+// The time between ticks, to tick at most `fps` times a second.
+fn frame_interval(fps: u32) -> Duration {
+    Duration::from_secs(1) / fps.max(1)
+}
+
+/// A single animation, that keeps its own Id so it doesn't have to be
+/// passed around separately. The animation itself still lives in a [`Timeline`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Animation {
+    id: widget::Id,
+}
+
+impl Animation {
+    /// Creates an [`Animation`] for an existing Id.
+    pub fn new(id: impl Into<widget::Id>) -> Self {
+        Animation { id: id.into() }
+    }
+
+    /// Creates an [`Animation`] with a unique Id.
+    #[must_use]
+    pub fn unique() -> Self {
+        Animation {
+            id: widget::Id::unique(),
+        }
+    }
+
+    /// The Id of this animation.
+    #[must_use]
+    pub fn id(&self) -> &widget::Id {
+        &self.id
+    }
+
+    /// Sets the chain for this animation, in place of the chain's own Id.
+    /// Like [`Timeline::set_chain`], it won't play until the timeline is started.
+    pub fn set<'a>(&self, timeline: &'a mut Timeline, chain: impl Into<Chain>) -> &'a mut Timeline {
+        let mut chain = chain.into();
+        chain.id = self.id.clone();
+        timeline.set_chain(chain)
+    }
+
+    /// The current value of the first modifier of this animation.
+    /// See [`Animation::get`] for any other modifier.
+    #[must_use]
+    pub fn value(&self, timeline: &Timeline) -> Option<f32> {
+        self.get(timeline, 0).map(|interped| interped.value)
+    }
+
+    /// The same as [`Timeline::get`] for this animation.
+    #[must_use]
+    pub fn get(&self, timeline: &Timeline, index: usize) -> Option<Interped> {
+        timeline.get(&self.id, index)
+    }
+
+    /// Check if this animation has played to its end. An animation that
+    /// loops forever is never done, unless stopped by
+    /// [`Timeline::set_reduced_motion`], and one that was never set always is.
+    #[must_use]
+    pub fn is_done(&self, timeline: &Timeline) -> bool {
+        let Some((meta, _track)) = timeline.tracks.get(&self.id) else {
+            return true;
+        };
+        let now = timeline.get_now();
+        if timeline.reduced_motion() {
+            return !timeline.is_moving(meta, now);
+        }
+        !meta.loops_forever() && meta.relative_now(now) >= meta.end
+    }
+
+    /// Pause this animation. See [`Timeline::pause`].
+    pub fn pause<'a>(&self, timeline: &'a mut Timeline) -> &'a mut Timeline {
+        timeline.pause(self.id.clone())
+    }
+
+    /// Resume this animation. See [`Timeline::resume`].
+    pub fn resume<'a>(&self, timeline: &'a mut Timeline) -> &'a mut Timeline {
+        timeline.resume(self.id.clone())
+    }
+}
+
+// The final value of a single modifier's timeline, as if it had played to the end.
+fn settled(modifier_timeline: &[SubFrame]) -> Option<Interped> {
+    let last = modifier_timeline.last()?;
+    let previous = modifier_timeline
+        .len()
+        .checked_sub(2)
+        .map_or(last.value, |i| modifier_timeline[i].value);
+    Some(Interped {
+        previous,
+        next: last.value,
+        value: last.value,
+        percent: 1.0,
+    })
+}
+
+// Interpolates a single modifier's timeline at a relative instant.
+fn interp(modifier_timeline: &[SubFrame], relative_now: Instant) -> Option<Interped> {
+    // Skip ahead to the last keyframe that has started. SubFrames are sorted
+    // by `at`, and the loop below would pass every keyframe before it anyway.
+    let start = modifier_timeline
+        .partition_point(|modifier| modifier.at <= relative_now)
+        .saturating_sub(1);
+    let mut modifier_timeline = modifier_timeline[start..].iter();
+    // Loop through modifier_timeline, returning the interpolated value if possible.
+    let mut accumulator: Option<&SubFrame> = None;
+    loop {
+        match (accumulator, modifier_timeline.next()) {
+            // Found first element in timeline
+            (None, Some(modifier)) => accumulator = Some(modifier),
+            // No Elements in timeline
+            (None, None) => return None,
+            // Accumulator found in previous loop, but no greater value. Means animation duration has expired.
+            (Some(acc), None) => {
+                return Some(Interped {
+                    previous: acc.value,
+                    next: acc.value,
+                    percent: 1.0,
+                    value: acc.value,
+                });
+            }
+            // Found accumulator in middle-ish of timeline
+            (Some(acc), Some(modifier)) => {
+                // Can not interpolate between this one and next value?
+                if relative_now >= modifier.at || acc.value == modifier.value {
+                    accumulator = Some(modifier);
+                // Can interpolate between these two, thus calculate and return that value.
+                } else {
+                    let elapsed = relative_now.duration_since(acc.at).as_nanos() as f64;
+                    let duration = (modifier.at - acc.at).as_nanos() as f64;
+
+                    let previous = acc.value;
+                    let next = modifier.value;
+                    let percent = modifier.ease.tween((elapsed / duration) as f32);
+                    let value = lerp(
+                        acc.value,
+                        modifier.value,
+                        modifier.ease.tween((elapsed / duration) as f32),
+                    );
+
+                    return Some(Interped {
+                        previous,
+                        next,
+                        value,
+                        percent,
+                    });
+                }
+            }
+        }
+    }
+}
+
+// Rebuilds a track to play from its position at `now` back to its start.
+fn reverse_track(meta: &mut Meta, track: &mut [SubFrames], now: Instant) {
+    let position = meta.relative_now(now).min(meta.end);
+    let elapsed = position.saturating_duration_since(meta.start);
+    for modifier_timeline in track.iter_mut() {
+        let Some(current) = interp(modifier_timeline, position) else {
+            continue;
+        };
+        let mut reversed: SubFrames =
+            smallvec![SubFrame::new(now, current.value, Linear::InOut.into())];
+        let split = modifier_timeline.partition_point(|frame| frame.at < position);
+        // Going forward, the ease into a frame covered the segment before it.
+        // Going backward that same segment is covered into the earlier frame.
+        let mut ease = modifier_timeline
+            .get(split)
+            .map_or(Linear::InOut.into(), |frame| frame.ease);
+        for frame in modifier_timeline[..split].iter().rev() {
+            reversed.push(SubFrame::new(
+                now + (position - frame.at),
+                frame.value,
+                ease,
+            ));
+            ease = frame.ease;
+        }
+        *modifier_timeline = reversed;
+    }
+    let pause = if meta.pause.is_playing() {
+        Pause::NoPause
+    } else {
+        Pause::Paused(now)
+    };
+    let rate = meta.rate;
+    *meta = Meta::new(Repeat::Never, now, now + elapsed, elapsed, pause);
+    if let Some(Rate { rate, .. }) = rate {
+        meta.set_rate(now, rate);
+    }
 }
 
 // Used for animations that loop.
 // Given the current `Instant`, it returns the relative instant in the animation that
 // corresponds with the first loop of the animation.
 fn relative_time(now: &Instant, meta: &Meta) -> Instant {
-    if meta.repeat == Repeat::Never {
+    // Once a finite number of loops is done, time is past the last keyframe
+    // so the animation holds it.
+    let is_done_looping = matches!(meta.repeat, Repeat::Times(_)) && *now >= meta.end;
+    // A zero-length loop has nothing to repeat, and would divide by zero.
+    let is_too_short = meta.length.is_zero();
+    if meta.repeat == Repeat::Never || is_done_looping || is_too_short {
         *now
+    } else if meta.repeat == Repeat::PingPong {
+        // Every other pass plays backward, mirrored in time so there is no
+        // jump at the turn around.
+        let elapsed = now.saturating_duration_since(meta.start).as_nanos();
+        let length = meta.length.as_nanos();
+        let within = elapsed % length;
+        let within = if (elapsed / length) % 2 == 1 {
+            length - within
+        } else {
+            within
+        };
+        meta.start + Duration::from_nanos(within as u64)
     } else {
-        let repeat_num = (*now - meta.start).as_millis() / meta.length.as_millis();
-        let reduce_by = repeat_num * meta.length.as_millis();
-        now.checked_sub(Duration::from_millis(
-            reduce_by.clamp(0, u64::MAX.into()).try_into().unwrap(),
-        ))
-        .expect("Your animatiion has been runnning for 5.84x10^6 centuries.")
+        let elapsed = now.saturating_duration_since(meta.start).as_nanos();
+        let reduce_by = elapsed - elapsed % meta.length.as_nanos();
+        *now - Duration::from_nanos(
+            reduce_by
+                .try_into()
+                .expect("Your animation has been running for 5.84 centuries."),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{chain, id, toggler};
+
+    fn toggler_chain(id: &id::Toggler) -> crate::chain::Toggler {
+        chain!(
+            id,
+            toggler(Duration::ZERO).percent(0.0),
+            toggler(Duration::from_millis(100)).percent(1.0),
+        )
+    }
+
+    #[test]
+    fn now_instant() {
+        let mut timeline = Timeline::new();
+        assert!(timeline.now_instant().is_none());
+
+        let now = Instant::now();
+        timeline.now(now);
+        assert_eq!(Some(now), timeline.now_instant());
+
+        let mut timeline = Timeline::new();
+        let id = id::Toggler::unique();
+        timeline.set_chain(toggler_chain(&id)).start_at(now);
+        assert_eq!(Some(now), timeline.now_instant());
+    }
+
+    #[test]
+    fn hold_until_end() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(chain!(
+                id,
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(Duration::from_millis(100))
+                    .percent(1.0)
+                    .ease(crate::Hold::End),
+            ))
+            .start_at(now);
+
+        let id = widget::Id::from(id);
+        for millis in [0, 50, 99] {
+            timeline.now(now + Duration::from_millis(millis));
+            assert_eq!(0.0, timeline.get(&id, 0).unwrap().value);
+        }
+        timeline.now(now + Duration::from_millis(100));
+        assert_eq!(1.0, timeline.get(&id, 0).unwrap().value);
+    }
+
+    #[test]
+    fn set_and_start() {
+        let now = Instant::now();
+        let first = id::Toggler::unique();
+        let second = id::Cards::unique();
+        let mut timeline = Timeline::new();
+        timeline.set_and_start_at(
+            [
+                Chain::from(toggler_chain(&first)),
+                Chain::from(chain!(
+                    second,
+                    crate::cards(Duration::ZERO).percent(0.0),
+                    crate::cards(Duration::from_millis(200)).percent(1.0),
+                )),
+            ],
+            now,
+        );
+        assert!(timeline.pendings.is_empty());
+        assert_eq!(now, timeline.tracks[&first.clone().into()].0.start);
+        assert_eq!(now, timeline.tracks[&second.clone().into()].0.start);
+
+        let mut timeline = Timeline::new();
+        crate::chains!(
+            timeline,
+            toggler_chain(&first),
+            chain!(
+                second,
+                crate::cards(Duration::ZERO).percent(0.0),
+                crate::cards(Duration::from_millis(200)).percent(1.0),
+            ),
+        );
+        assert_eq!(
+            timeline.tracks[&first.into()].0.start,
+            timeline.tracks[&second.into()].0.start
+        );
+    }
+
+    #[test]
+    fn quantize() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline
+            .quantize(Duration::from_millis(33))
+            .set_chain(toggler_chain(&id))
+            .start_at(now);
+        let id = widget::Id::from(id);
+
+        let mut value_at = |millis| {
+            timeline.now(now + Duration::from_millis(millis));
+            timeline.get(&id, 0).unwrap().value
+        };
+        assert_eq!(value_at(33), value_at(40));
+        assert_eq!(value_at(33), value_at(65));
+        assert_eq!(0.33, value_at(65));
+        assert_ne!(value_at(65), value_at(66));
+        assert_eq!(0.0, value_at(32));
+    }
+
+    #[test]
+    fn bounds() {
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let sized = |id: &widget::Id, from: (f32, f32), to: (f32, f32)| {
+            let frame = |millis, value| {
+                Some(Frame::eager(
+                    Duration::from_millis(millis),
+                    value,
+                    crate::Linear::InOut.into(),
+                ))
+            };
+            Chain::new(
+                id.clone(),
+                Repeat::Never,
+                vec![
+                    vec![frame(0, from.0), frame(0, from.1)],
+                    vec![frame(100, to.0), frame(100, to.1)],
+                ],
+            )
+        };
+
+        let first = widget::Id::unique();
+        let second = widget::Id::unique();
+        let mut timeline = Timeline::new();
+        timeline
+            .set_chain(sized(&first, (10.0, 50.0), (30.0, 50.0)))
+            .set_chain(sized(&second, (20.0, 0.0), (20.0, 100.0)))
+            .start_at(now);
+        let ids = [(first, 0, 1), (second, 0, 1), (widget::Id::unique(), 0, 1)];
+
+        timeline.now(at(0));
+        assert_eq!(Some(Size::new(20.0, 50.0)), timeline.bounds(&ids));
+        timeline.now(at(75));
+        assert_eq!(Some(Size::new(25.0, 75.0)), timeline.bounds(&ids));
+        timeline.now(at(100));
+        assert_eq!(Some(Size::new(30.0, 100.0)), timeline.bounds(&ids));
+        assert_eq!(None, timeline.bounds(&ids[2..]));
+    }
+
+    #[test]
+    fn reverse_all() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let first = id::Toggler::unique();
+        let second = id::Toggler::unique();
+        timeline
+            .set_chain(toggler_chain(&first))
+            .set_chain(chain!(
+                second,
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(Duration::from_millis(200)).percent(1.0),
+            ))
+            .start_at(now);
+        let first = widget::Id::from(first);
+        let second = widget::Id::from(second);
+        let values = |timeline: &Timeline| {
+            let r = |id| (timeline.get(id, 0).unwrap().value * 1000.0).round() / 1000.0;
+            (r(&first), r(&second))
+        };
+
+        timeline.now(at(60));
+        assert_eq!((0.6, 0.3), values(&timeline));
+        timeline.reverse_all().start_at(at(60));
+        assert_eq!((0.6, 0.3), values(&timeline));
+        timeline.now(at(90));
+        assert_eq!((0.3, 0.15), values(&timeline));
+        timeline.now(at(120));
+        assert_eq!((0.0, 0.0), values(&timeline));
+        timeline.now(at(500));
+        assert_eq!((0.0, 0.0), values(&timeline));
+    }
+
+    #[test]
+    fn tiny_speed_segment() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(chain!(
+                id,
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(crate::Speed::per_nanos(1.0)).percent(0.0001),
+            ))
+            .start_at(now);
+        let id = widget::Id::from(id);
+
+        assert_eq!(now + Duration::from_nanos(1), timeline.tracks[&id].0.end);
+        for nanos in [0, 1, 2] {
+            timeline.now(now + Duration::from_nanos(nanos));
+            assert!(!timeline.get(&id, 0).unwrap().value.is_nan());
+        }
+        assert_eq!(0.0001, timeline.get(&id, 0).unwrap().value);
+    }
+
+    #[test]
+    fn animation() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let animation = Animation::unique();
+        assert!(animation.is_done(&timeline));
+        assert_eq!(None, animation.value(&timeline));
+
+        animation
+            .set(&mut timeline, toggler_chain(&id::Toggler::unique()))
+            .start_at(now);
+        for millis in [0, 50, 100] {
+            timeline.now(at(millis));
+            assert_eq!(
+                timeline.get(animation.id(), 0).map(|i| i.value),
+                animation.value(&timeline)
+            );
+        }
+        assert_eq!(Some(1.0), animation.value(&timeline));
+
+        timeline.now(at(50));
+        assert!(!animation.is_done(&timeline));
+        timeline.now(at(100));
+        assert!(animation.is_done(&timeline));
+
+        // A paused animation isn't done until it has played the rest.
+        animation
+            .set(&mut timeline, toggler_chain(&id::Toggler::unique()))
+            .start_at(at(200));
+        animation.pause(&mut timeline).start_at(at(250));
+        timeline.now(at(400));
+        assert!(!animation.is_done(&timeline));
+        assert_eq!(Some(0.5), animation.value(&timeline));
+        animation.resume(&mut timeline).start_at(at(400));
+        timeline.now(at(449));
+        assert!(!animation.is_done(&timeline));
+        timeline.now(at(450));
+        assert!(animation.is_done(&timeline));
+    }
+
+    #[test]
+    fn get_prev() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline.set_chain(toggler_chain(&id)).start_at(now);
+        let id = widget::Id::from(id);
+        let interped = timeline.get(&id, 0).unwrap();
+        assert_eq!(Some(interped.value), timeline.get_prev(&id, 0));
+
+        timeline.now(now + Duration::from_millis(50));
+        let previous = timeline.get(&id, 0).unwrap().value;
+        timeline.now(now + Duration::from_millis(80));
+        // Setting the same instant twice doesn't lose the previous frame.
+        timeline.now(now + Duration::from_millis(80));
+        assert_eq!(Some(previous), timeline.get_prev(&id, 0));
+        assert_eq!(0.8, timeline.get(&id, 0).unwrap().value);
+    }
+
+    #[test]
+    fn idle_subscription_is_none() {
+        let is_none = |timeline: &Timeline| {
+            cosmic::iced_futures::subscription::into_recipes(timeline.as_subscription()).is_empty()
+        };
+        let mut timeline = Timeline::new();
+        assert!(is_none(&timeline));
+
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline.set_chain(toggler_chain(&id)).start_at(now);
+        timeline.now(now + Duration::from_millis(101));
+        assert!(timeline.is_idle());
+        assert!(is_none(&timeline));
+    }
+
+    #[test]
+    fn try_get() {
+        let mut timeline = Timeline::new();
+        let id = id::Toggler::unique();
+        let unknown = widget::Id::unique();
+        timeline.set_chain(toggler_chain(&id)).start();
+        let id = widget::Id::from(id);
+
+        assert!(timeline.try_get(&id, 0).is_ok());
+        assert_eq!(
+            Err(GetError::OutOfRange { index: 1, len: 1 }),
+            timeline.try_get(&id, 1).map(|i| i.value)
+        );
+        assert_eq!(
+            Err(GetError::NoAnimation),
+            timeline.try_get(&unknown, 0).map(|i| i.value)
+        );
+        assert!(timeline.get(&id, 1).is_none());
+    }
+
+    #[test]
+    fn get_vector() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = crate::id::Offset::unique();
+        timeline
+            .set_chain(crate::chain::Offset::slide_in(
+                id.clone(),
+                crate::Edge::Top,
+                80.0,
+                Duration::from_millis(100),
+                crate::Quadratic::Out,
+            ))
+            .start_at(now);
+        let id = widget::Id::from(id);
+
+        for millis in [0, 30, 60, 100] {
+            timeline.now(now + Duration::from_millis(millis));
+            let vector = timeline.get_vector(&id).unwrap();
+            assert_eq!(timeline.get(&id, 0).unwrap().value, vector.x);
+            assert_eq!(timeline.get(&id, 1).unwrap().value, vector.y);
+        }
+        // A track with a single modifier has no y.
+        let toggler = id::Toggler::unique();
+        timeline.set_chain(toggler_chain(&toggler)).start_at(now);
+        assert_eq!(None, timeline.get_vector(&toggler.into()));
+    }
+
+    #[test]
+    fn prune_pending() {
+        let mut timeline = Timeline::new();
+        let first = id::Toggler::unique();
+        let second = id::Toggler::unique();
+        let _ = timeline
+            .set_chain(toggler_chain(&first))
+            .set_chain(toggler_chain(&second));
+
+        assert!(timeline.prune_pending(first.clone()));
+        assert!(!timeline.prune_pending(first.clone()));
+        timeline.start();
+        assert!(timeline.get(&first.into(), 0).is_none());
+        assert!(timeline.get(&second.into(), 0).is_some());
+    }
+
+    #[test]
+    fn map_ids() {
+        let mut timeline = Timeline::new();
+        let started = id::Toggler::unique();
+        let pending = id::Toggler::unique();
+        let _ = timeline.set_chain(toggler_chain(&started));
+        timeline.start();
+        let _ = timeline.set_chain(toggler_chain(&pending)).pause_all();
+
+        let (new_started, new_pending) = (widget::Id::unique(), widget::Id::unique());
+        timeline.map_ids(|id| {
+            if *id == widget::Id::from(started.clone()) {
+                new_started.clone()
+            } else {
+                new_pending.clone()
+            }
+        });
+        timeline.start();
+
+        assert!(timeline.get(&started.into(), 0).is_none());
+        assert!(timeline.get(&pending.into(), 0).is_none());
+        assert!(timeline.get(&new_started, 0).is_some());
+        assert!(timeline.get(&new_pending, 0).is_some());
+        // The pause_all pending was kept, and still applied to everything.
+        assert!(!timeline.tracks[&new_started].0.pause.is_playing());
+    }
+
+    #[test]
+    fn set_track() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let id = widget::Id::unique();
+        let ease: Ease = Linear::InOut.into();
+        let _ = timeline.set_track(
+            id.clone(),
+            Meta::new(
+                Repeat::Never,
+                now,
+                at(100),
+                Duration::from_millis(100),
+                Pause::NoPause,
+            ),
+            vec![
+                vec![
+                    SubFrame::new(now, 0.0, ease),
+                    SubFrame::new(at(100), 10.0, ease),
+                ],
+                vec![
+                    SubFrame::new(now, 4.0, ease),
+                    SubFrame::new(at(100), 2.0, ease),
+                ],
+            ],
+        );
+
+        timeline.now(at(50));
+        assert_eq!(5.0, timeline.get(&id, 0).unwrap().value);
+        assert_eq!(3.0, timeline.get(&id, 1).unwrap().value);
+        assert!(timeline.get(&id, 2).is_none());
+        timeline.now(at(150));
+        assert_eq!(10.0, timeline.get(&id, 0).unwrap().value);
+        assert!(timeline.is_idle());
+    }
+
+    #[test]
+    fn reduced_motion() {
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_reduced_motion(true);
+        let now = Instant::now();
+        let toggler = id::Toggler::unique();
+        let cards = id::Cards::unique();
+        let looping = id::Toggler::unique();
+        timeline
+            .set_chain(crate::chain::Toggler::on(toggler.clone(), 1.0))
+            .set_chain(crate::chain::Cards::on(cards.clone(), 1.0))
+            .set_chain(toggler_chain(&looping).loop_forever())
+            .start_at(now);
+
+        // Snapped on the very first frame.
+        assert_eq!(
+            Some(1.0),
+            timeline.get(&toggler.clone().into(), 0).map(|i| i.value)
+        );
+        assert_eq!(Some(1.0), timeline.get(&cards.into(), 0).map(|i| i.value));
+        assert_eq!(Some(1.0), timeline.get(&looping.into(), 0).map(|i| i.value));
+
+        // Still updates state, without animating.
+        timeline.set_and_start_at([crate::chain::Toggler::off(toggler.clone(), 1.0)], now);
+        assert_eq!(Some(0.0), timeline.get(&toggler.into(), 0).map(|i| i.value));
+    }
+
+    #[test]
+    fn reduced_motion_settles_running() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let (running, looping, paused) = (
+            id::Toggler::unique(),
+            id::Toggler::unique(),
+            id::Toggler::unique(),
+        );
+        timeline
+            .set_chain(toggler_chain(&running))
+            .set_chain(toggler_chain(&looping).loop_forever())
+            .set_chain_paused(toggler_chain(&paused))
+            .start_at(now);
+        timeline.now(now + Duration::from_millis(50));
+        assert_eq!(Some(0.5), timeline.two_state(&running.clone().into()));
+        assert_eq!(Some(0.0), timeline.two_state(&paused.clone().into()));
+
+        // Enabling it mid-animation jumps everything to its last keyframe.
+        let _ = timeline.set_reduced_motion(true);
+        for id in [&running, &looping, &paused] {
+            let interped = timeline.get(&id.clone().into(), 0).unwrap();
+            assert_eq!(1.0, interped.value);
+            assert_eq!(1.0, interped.percent);
+        }
+        timeline.now(now + Duration::from_millis(130));
+        assert_eq!(Some(1.0), timeline.two_state(&looping.clone().into()));
+
+        let _ = timeline.set_reduced_motion(false);
+        assert_eq!(Some(0.3), timeline.two_state(&looping.into()));
+    }
+
+    #[test]
+    fn reduced_motion_stops() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let (running, looping, delayed) = (
+            id::Toggler::unique(),
+            id::Toggler::unique(),
+            id::Toggler::unique(),
+        );
+        timeline
+            .set_chain(toggler_chain(&running))
+            .set_chain(toggler_chain(&looping).loop_forever())
+            .start_at(now);
+        timeline.now(at(50));
+        let _ = timeline.set_reduced_motion(true);
+        assert!(!timeline.is_idle());
+        assert!(!Animation::new(looping.clone()).is_done(&timeline));
+
+        // Running animations stop on the next frame, and finish there.
+        timeline.now(at(66));
+        let mut completed: Vec<_> = timeline.completed().cloned().collect();
+        completed.sort_by_key(|id| widget::Id::from(running.clone()) != *id);
+        assert_eq!(
+            vec![widget::Id::from(running.clone()), looping.clone().into()],
+            completed
+        );
+        assert!(timeline.is_idle());
+        assert_eq!(0, timeline.active_count());
+        assert!(Animation::new(looping.clone()).is_done(&timeline));
+        assert!(!timeline.now_changed(at(82)));
+        assert_eq!(0, timeline.completed().count());
+
+        // Delays are skipped, so new animations finish on their first frame.
+        timeline
+            .set_chain(toggler_chain(&delayed).delay(Duration::from_secs(1)))
+            .start_at(at(90));
+        assert!(timeline.is_animating(&delayed.clone().into()));
+        assert!(timeline.now_changed(at(98)));
+        assert_eq!(
+            vec![&widget::Id::from(delayed.clone())],
+            timeline.completed().collect::<Vec<_>>()
+        );
+        assert!(!timeline.is_animating(&delayed.into()));
+        assert!(timeline.is_idle());
+    }
+
+    #[test]
+    fn reduced_motion_vector() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = crate::id::Offset::unique();
+        timeline
+            .set_chain(chain!(
+                id.clone(),
+                crate::offset(Duration::ZERO).x(0.0).y(0.0),
+                crate::offset(Duration::from_millis(100)).x(30.0).y(-10.0),
+            ))
+            .start_at(now);
+        timeline.now(now + Duration::from_millis(50));
+        let id = widget::Id::from(id);
+        assert_eq!(Some(Vector::new(15.0, -5.0)), timeline.get_vector(&id));
+
+        let _ = timeline.set_reduced_motion(true);
+        assert_eq!(Some(Vector::new(30.0, -10.0)), timeline.get_vector(&id));
+        assert_eq!(Some(1.0), timeline.progress(&id));
+        assert_eq!(Some(Duration::ZERO), timeline.remaining(&id));
+    }
+
+    #[test]
+    fn get_along_path() {
+        let mut timeline = Timeline::new();
+        let id = id::Path::unique();
+        // An L, 30 pixels right and then 10 down, taking the same time for both.
+        timeline
+            .set_chain(chain!(
+                id,
+                crate::path(Duration::ZERO).to(Point::new(0.0, 0.0)),
+                crate::path(Duration::from_millis(100)).to(Point::new(30.0, 0.0)),
+                crate::path(Duration::from_millis(100)).to(Point::new(30.0, 10.0)),
+            ))
+            .start();
+        let along = |t| id.along(&timeline, t);
+
+        assert_eq!(Some(Point::new(0.0, 0.0)), along(0.0));
+        assert_eq!(Some(Point::new(30.0, 10.0)), along(1.0));
+        // By distance, not time, so halfway is still on the first leg.
+        assert_eq!(Some(Point::new(20.0, 0.0)), along(0.5));
+        assert_eq!(Some(Point::new(30.0, 0.0)), along(0.75));
+        assert_eq!(Some(Point::new(30.0, 5.0)), along(0.875));
+        for t in [0.1, 0.3, 0.6, 0.9] {
+            let point = along(t).unwrap();
+            assert!(
+                point.y == 0.0 || point.x == 30.0,
+                "{point:?} is off the path"
+            );
+        }
+        assert_eq!(along(1.0), along(4.0));
+        assert!(timeline
+            .get_along_path(&id::Path::unique().into(), 0.5)
+            .is_none());
+    }
+
+    #[test]
+    fn progress() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let once = id::Toggler::unique();
+        let looping = id::Toggler::unique();
+        timeline
+            .set_chain(toggler_chain(&once))
+            .set_chain(toggler_chain(&looping).loop_forever())
+            .start_at(now);
+        let (once, looping) = (widget::Id::from(once), widget::Id::from(looping));
+        assert_eq!(None, timeline.progress(&widget::Id::unique()));
+        assert_eq!(Some(0.0), timeline.progress(&once));
+
+        timeline.now(at(42));
+        assert_eq!(Some(0.42), timeline.progress(&once));
+        timeline.now(at(250));
+        assert_eq!(Some(1.0), timeline.progress(&once));
+        assert_eq!(Some(0.5), timeline.progress(&looping));
+
+        // Paused animations hold their progress.
+        let _ = timeline.pause(once.clone()).pause(looping.clone());
+        timeline.start_at(at(250));
+        timeline.now(at(300));
+        assert_eq!(Some(0.5), timeline.progress(&looping));
+    }
+
+    #[test]
+    fn timed() {
+        let now = Instant::now();
+        let subframes = |chain: Chain| {
+            let mut timeline = Timeline::new();
+            let id = chain.id.clone();
+            timeline.set_chain(chain).start_at(now);
+            timeline.tracks[&id].1[0]
+                .iter()
+                .map(|subframe| (subframe.at - now, subframe.value))
+                .collect::<Vec<_>>()
+        };
+        let id = id::Toggler::unique();
+        let manual = chain!(
+            id.clone(),
+            toggler(Duration::ZERO).percent(0.0),
+            toggler(Duration::from_secs(1)).percent(1.0),
+        );
+        let timed = Chain::timed(id.clone(), Duration::from_secs(2))
+            .at_percent(0.0, toggler(Duration::ZERO).percent(0.0))
+            .at_percent(50.0, toggler(Duration::ZERO).percent(1.0));
+        assert_eq!(subframes(manual.into()), subframes(timed.into()));
+
+        // The first stop is held until it's reached.
+        let timed = Chain::timed(id.clone(), Duration::from_secs(2))
+            .at_percent(25.0, toggler(Duration::ZERO).percent(0.0))
+            .at_percent(100.0, toggler(Duration::ZERO).percent(1.0));
+        assert_eq!(
+            vec![
+                (Duration::ZERO, 0.0),
+                (Duration::from_millis(500), 0.0),
+                (Duration::from_secs(2), 1.0),
+            ],
+            subframes(timed.into())
+        );
+    }
+
+    #[test]
+    #[should_panic = "must be within 50% and 100%"]
+    fn timed_out_of_order() {
+        let _ = Chain::timed(id::Toggler::unique(), Duration::from_secs(1))
+            .at_percent(50.0, toggler(Duration::ZERO))
+            .at_percent(25.0, toggler(Duration::ZERO));
+    }
+
+    #[test]
+    fn remaining() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let once = id::Toggler::unique();
+        let looping = id::Toggler::unique();
+        timeline
+            .set_chain(toggler_chain(&once).loop_n(2))
+            .set_chain(toggler_chain(&looping).loop_forever())
+            .start_at(now);
+        let (once, looping) = (widget::Id::from(once), widget::Id::from(looping));
+        assert_eq!(None, timeline.remaining(&widget::Id::unique()));
+        assert_eq!(None, timeline.remaining(&looping));
+
+        timeline.now(at(30));
+        assert_eq!(Some(Duration::from_millis(170)), timeline.remaining(&once));
+        timeline.now(at(250));
+        assert_eq!(Some(Duration::ZERO), timeline.remaining(&once));
+    }
+
+    #[test]
+    fn remaining_while_paused() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        timeline.set_chain(toggler_chain(&id)).start_at(now);
+        let id = widget::Id::from(id);
+        timeline.now(at(40));
+        let _ = timeline.pause(id.clone());
+        timeline.start_at(at(40));
+
+        timeline.now(at(90));
+        assert_eq!(Some(Duration::from_millis(60)), timeline.remaining(&id));
+
+        // Resuming counts down from where it was paused.
+        let _ = timeline.resume(id.clone());
+        timeline.start_at(at(90));
+        timeline.now(at(110));
+        assert_eq!(Some(Duration::from_millis(40)), timeline.remaining(&id));
+    }
+
+    #[test]
+    fn delay() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(toggler_chain(&id).delay(Duration::from_millis(500)))
+            .start_at(now);
+        let id = widget::Id::from(id);
+
+        // The first keyframe is shown while waiting, and frames are still
+        // requested so the animation starts on time.
+        for millis in [0, 250, 500] {
+            timeline.now(at(millis));
+            assert_eq!(Some(0.0), timeline.two_state(&id));
+            assert!(timeline.is_animating(&id));
+        }
+        timeline.now(at(550));
+        assert_eq!(Some(0.5), timeline.two_state(&id));
+        timeline.now(at(601));
+        assert_eq!(Some(1.0), timeline.two_state(&id));
+        assert!(timeline.is_idle());
+    }
+
+    #[test]
+    fn stagger() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let stagger = Duration::from_millis(30);
+        let ids: Vec<_> = (0..5).map(|_| id::Toggler::unique()).collect();
+        timeline.set_chains_staggered_at(ids.iter().map(toggler_chain), stagger, now);
+
+        for (k, id) in ids.into_iter().enumerate() {
+            let id = widget::Id::from(id);
+            let begins = now + stagger * k as u32;
+            assert_eq!(begins, timeline.tracks[&id].0.start);
+            timeline.now(begins);
+            assert_eq!(Some(0.0), timeline.two_state(&id));
+            timeline.now(begins + Duration::from_millis(10));
+            assert_eq!(Some(0.1), timeline.two_state(&id));
+        }
+    }
+
+    #[test]
+    fn capped_frames() {
+        assert_eq!(Duration::from_secs(1), frame_interval(0));
+        let interval = frame_interval(30);
+        assert!(interval >= Duration::from_secs(1) / 30);
+
+        // Ticking a second long animation at the cap, like the timer does.
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(chain!(
+                id.clone(),
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(Duration::from_secs(1)).percent(1.0),
+            ))
+            .start_at(now);
+        let mut ticks = 0;
+        let mut tick = now;
+        while !timeline.is_idle() {
+            tick += interval;
+            timeline.now(tick);
+            ticks += 1;
+        }
+        assert_eq!(31, ticks);
+        assert_eq!(Some(1.0), timeline.two_state(&id.into()));
+    }
+
+    // A timeline on a clock that only moves when told to, a function to set
+    // the clock to some milliseconds from the start, and the start.
+    fn manual_clock() -> (Timeline, impl Fn(u64), Instant) {
+        let start = Instant::now();
+        let time = Arc::new(std::sync::Mutex::new(start));
+        let clock = Arc::clone(&time);
+        let timeline = Timeline::with_clock(move || *clock.lock().unwrap());
+        let set = move |millis| *time.lock().unwrap() = start + Duration::from_millis(millis);
+        (timeline, set, start)
+    }
+
+    #[test]
+    fn with_clock() {
+        let (mut timeline, set_clock, start) = manual_clock();
+        let at = |millis| start + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        set_clock(30);
+        assert_eq!(at(30), timeline.get_now());
+        timeline.set_chain(toggler_chain(&id)).start();
+        let id = widget::Id::from(id);
+        assert_eq!(at(30), timeline.tracks[&id].0.start);
+
+        set_clock(500);
+        assert_eq!(Some(0.0), timeline.two_state(&id));
+        timeline.now(at(55));
+        assert_eq!(Some(0.25), timeline.two_state(&id));
+    }
+
+    #[test]
+    fn with_clock_interp() {
+        let (mut timeline, set_clock, start) = manual_clock();
+        let id = id::Toggler::unique();
+        set_clock(1000);
+        timeline
+            .set_chain(chain!(
+                id.clone(),
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(Duration::from_millis(100))
+                    .percent(1.0)
+                    .ease(crate::Quadratic::In),
+                toggler(Duration::from_millis(100))
+                    .percent(0.0)
+                    .ease(crate::Quadratic::Out),
+                toggler(Duration::from_millis(100)).percent(0.5),
+            ))
+            .start();
+        let id = widget::Id::from(id);
+        let expected = [
+            (0, 0.0),
+            (50, 0.25),
+            (100, 1.0),
+            (150, 0.25),
+            (200, 0.0),
+            (250, 0.25),
+            (300, 0.5),
+            (400, 0.5),
+        ];
+        for (millis, value) in expected {
+            timeline.now(start + Duration::from_millis(1000 + millis));
+            assert_eq!(Some(value), timeline.two_state(&id), "at {millis}ms");
+        }
+    }
+
+    #[test]
+    fn with_clock_paused() {
+        let (mut timeline, set_clock, start) = manual_clock();
+        let at = |millis| start + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        set_clock(40);
+        timeline.set_chain_paused(toggler_chain(&id)).start();
+        let id = widget::Id::from(id);
+        timeline.now(at(90));
+        assert_eq!(Some(0.0), timeline.two_state(&id));
+
+        set_clock(90);
+        let _ = timeline.resume(id.clone());
+        timeline.start();
+        timeline.now(at(120));
+        assert_eq!(Some(0.3), timeline.two_state(&id));
+    }
+
+    #[test]
+    fn repeated_pauses() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(toggler_chain(&id).loop_n(2))
+            .start_at(now);
+        let id = widget::Id::from(id);
+        let played =
+            |timeline: &Timeline| timeline.tracks[&id].0.relative_now(timeline.get_now()) - now;
+
+        // Paused for 30ms, then 40ms, then 10ms in the second loop. Each
+        // resume continues exactly where the pause was.
+        for (pause, resume, played_before) in [(20, 50, 20), (80, 120, 50), (230, 240, 60)] {
+            timeline.now(at(pause));
+            assert_eq!(Duration::from_millis(played_before), played(&timeline));
+            let _ = timeline.pause(id.clone());
+            timeline.start_at(at(pause));
+            timeline.now(at(resume));
+            let _ = timeline.resume(id.clone());
+            timeline.start_at(at(resume));
+            assert_eq!(Duration::from_millis(played_before), played(&timeline));
+        }
+        timeline.now(at(250));
+        assert_eq!(Duration::from_millis(70), played(&timeline));
+        assert_eq!(Some(0.7), timeline.two_state(&id));
+
+        // The time spent paused is added to the end, so it still animates.
+        timeline.now(at(275));
+        assert!(timeline.is_animating(&id));
+        assert_eq!(Some(Duration::from_millis(5)), timeline.remaining(&id));
+        timeline.now(at(280));
+        assert_eq!(Some(1.0), timeline.two_state(&id));
+    }
+
+    #[test]
+    fn is_animating() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        let _ = timeline.set_chain(toggler_chain(&id));
+        let id = widget::Id::from(id);
+        assert!(!timeline.is_animating(&id));
+        assert!(timeline.is_idle());
+
+        timeline.start_at(now);
+        for millis in [0, 50, 100] {
+            timeline.now(at(millis));
+            assert!(timeline.is_animating(&id));
+            assert!(!timeline.is_idle());
+        }
+        // Past `meta.end`.
+        timeline.now(at(101));
+        assert!(!timeline.is_animating(&id));
+        assert!(timeline.is_idle());
+        assert!(!timeline.is_animating(&widget::Id::unique()));
+    }
+
+    #[test]
+    fn start_without_pendings() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        timeline.set_chain(toggler_chain(&id)).start_at(now);
+        let capacity = timeline.pendings.capacity();
+        assert!(capacity > 0);
+
+        // Starting with nothing pending only moves time along.
+        timeline.start_at(at(50));
+        assert_eq!(Some(at(50)), timeline.now_instant());
+        assert_eq!(0.5, timeline.get(&id.clone().into(), 0).unwrap().value);
+        assert_eq!(capacity, timeline.pendings.capacity());
+
+        // The drained map is reused for the next chains.
+        timeline.set_chain(toggler_chain(&id)).start_at(at(100));
+        assert_eq!(0, timeline.pending_count());
+        assert_eq!(capacity, timeline.pendings.capacity());
+        timeline.now(at(150));
+        assert_eq!(0.5, timeline.get(&id.into(), 0).unwrap().value);
+    }
+
+    #[test]
+    fn active_ids() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let short = id::Toggler::unique();
+        let long = id::Toggler::unique();
+        timeline.set_and_start_at(
+            [
+                toggler_chain(&short),
+                chain!(
+                    long,
+                    toggler(Duration::ZERO),
+                    toggler(Duration::from_millis(300)),
+                ),
+            ],
+            now,
+        );
+        let (short, long) = (widget::Id::from(short), widget::Id::from(long));
+        assert_eq!(2, timeline.active_ids().count());
+
+        timeline.now(now + Duration::from_millis(200));
+        assert_eq!(vec![&long], timeline.active_ids().collect::<Vec<_>>());
+        // The finished animation is still there, just not running.
+        assert_eq!(2, timeline.all_ids().count());
+        assert!(timeline.all_ids().any(|id| id == &short));
+    }
+
+    #[test]
+    fn now_changed() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        timeline.set_chain(toggler_chain(&id)).start_at(now);
+
+        assert!(timeline.now_changed(at(50)));
+        assert!(!timeline.now_changed(at(50)));
+        // Past `meta.end`, the last bit of movement still needs a redraw.
+        assert!(timeline.now_changed(at(150)));
+        assert!(!timeline.now_changed(at(200)));
+        assert!(timeline.is_idle());
+    }
+
+    #[test]
+    fn loop_n() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(toggler_chain(&id).loop_n(3))
+            .start_at(now);
+        let id = widget::Id::from(id);
+        assert_eq!(now + Duration::from_millis(300), timeline.tracks[&id].0.end);
+
+        let mut value_at = |millis| {
+            timeline.now(now + Duration::from_millis(millis));
+            (timeline.get(&id, 0).unwrap().value * 100.0).round() / 100.0
+        };
+        assert_eq!(0.5, value_at(50));
+        assert_eq!(0.5, value_at(150));
+        assert_eq!(0.5, value_at(250));
+        assert_eq!(0.99, value_at(299));
+        // Settled on the last keyframe after the third loop.
+        assert_eq!(1.0, value_at(300));
+        assert_eq!(1.0, value_at(350));
+        assert_eq!(1.0, value_at(10_000));
+        assert!(timeline.is_idle());
+    }
+
+    #[test]
+    fn ping_pong() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(
+                chain!(
+                    id,
+                    toggler(Duration::ZERO).percent(0.0),
+                    toggler(Duration::from_millis(100))
+                        .percent(1.0)
+                        .ease(crate::Quadratic::In),
+                )
+                .ping_pong(),
+            )
+            .start_at(now);
+        let id = widget::Id::from(id);
+
+        let mut value_at = |millis| {
+            timeline.now(now + Duration::from_millis(millis));
+            (timeline.get(&id, 0).unwrap().value * 1000.0).round() / 1000.0
+        };
+        // The midpoints of a forward and a backward pass match.
+        assert_eq!(0.25, value_at(50));
+        assert_eq!(0.25, value_at(150));
+        assert_eq!(0.25, value_at(250));
+        // Continuous at the turn arounds.
+        assert_eq!(value_at(99), value_at(101));
+        assert_eq!(1.0, value_at(100));
+        assert_eq!(value_at(199), value_at(201));
+        assert_eq!(0.0, value_at(200));
+        assert!(!timeline.is_idle());
+    }
+
+    #[test]
+    fn zero_length_loop() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(
+                chain!(
+                    id,
+                    toggler(Duration::ZERO).percent(0.5),
+                    toggler(Duration::ZERO).percent(0.5),
+                )
+                .loop_forever(),
+            )
+            .start_at(now);
+        let id = widget::Id::from(id);
+
+        timeline.now(now + Duration::from_millis(100));
+        assert_eq!(0.5, timeline.get(&id, 0).unwrap().value);
+        assert!(timeline.is_idle());
+    }
+
+    #[test]
+    fn sub_millisecond_loop() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(
+                chain!(
+                    id,
+                    toggler(Duration::ZERO).percent(0.0),
+                    toggler(Duration::from_micros(500)).percent(1.0),
+                )
+                .loop_forever(),
+            )
+            .start_at(now);
+        let id = widget::Id::from(id);
+
+        // Two and a half loops in.
+        timeline.now(now + Duration::from_micros(1250));
+        assert_eq!(0.5, timeline.get(&id, 0).unwrap().value);
+        assert!(!timeline.is_idle());
+    }
+
+    #[test]
+    fn interp_long_chain() {
+        // The linear scan `interp` used before skipping ahead with a binary search.
+        fn scan(modifier_timeline: &[SubFrame], relative_now: Instant) -> Option<Interped> {
+            let mut accumulator: Option<&SubFrame> = None;
+            for modifier in modifier_timeline {
+                match accumulator {
+                    Some(acc) if relative_now < modifier.at && acc.value != modifier.value => {
+                        let elapsed = relative_now.duration_since(acc.at).as_nanos() as f64;
+                        let duration = (modifier.at - acc.at).as_nanos() as f64;
+                        let percent = modifier.ease.tween((elapsed / duration) as f32);
+                        let value = lerp(acc.value, modifier.value, percent);
+                        return Some(Interped {
+                            previous: acc.value,
+                            next: modifier.value,
+                            value,
+                            percent,
+                        });
+                    }
+                    _ => accumulator = Some(modifier),
+                }
+            }
+            accumulator.map(|acc| Interped {
+                previous: acc.value,
+                next: acc.value,
+                percent: 1.0,
+                value: acc.value,
+            })
+        }
+
+        let now = Instant::now();
+        // Runs of equal values, so holds are skipped over too.
+        let frames: Vec<_> = (0..1000u64)
+            .map(|i| {
+                let ease = if i % 3 == 0 {
+                    crate::Quadratic::In.into()
+                } else {
+                    Linear::InOut.into()
+                };
+                SubFrame::new(now + Duration::from_millis(i * 10), (i / 3) as f32, ease)
+            })
+            .collect();
+        let fields =
+            |interped: Option<Interped>| interped.map(|i| (i.previous, i.next, i.value, i.percent));
+        for millis in (0..10_020).step_by(7).chain([0, 10, 5000, 9990, 9991]) {
+            let at = now + Duration::from_millis(millis);
+            assert_eq!(
+                fields(scan(&frames, at)),
+                fields(interp(&frames, at)),
+                "at {millis}ms"
+            );
+        }
+        assert!(interp(&[], now).is_none());
+    }
+
+    #[test]
+    fn seek() {
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        let chain = || {
+            chain!(
+                id.clone(),
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(Duration::from_millis(100))
+                    .percent(1.0)
+                    .ease(crate::Quadratic::In),
+            )
+        };
+        let mut played = Timeline::new();
+        played.set_chain(chain()).start_at(now);
+        for millis in [10, 20, 30, 40, 50] {
+            played.now(at(millis));
+        }
+        let mut scrubbed = Timeline::new();
+        scrubbed.set_chain(chain()).start_at(now);
+        scrubbed.seek(at(150));
+        assert!(scrubbed.is_idle());
+        scrubbed.seek(at(50));
+        assert!(!scrubbed.is_idle());
+
+        let id = widget::Id::from(id.clone());
+        assert_eq!(Some(0.25), scrubbed.get_prev(&id, 0));
+        let played = played.get(&id, 0).unwrap();
+        let scrubbed = scrubbed.get(&id, 0).unwrap();
+        assert_eq!(0.25, scrubbed.value);
+        assert_eq!(played.value, scrubbed.value);
+    }
+
+    #[test]
+    fn rate() {
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let setup = |rate| {
+            let mut timeline = Timeline::new();
+            let id = id::Toggler::unique();
+            timeline
+                .set_chain(chain!(
+                    id.clone(),
+                    toggler(Duration::ZERO).percent(0.0),
+                    toggler(Duration::from_millis(100)).percent(1.0),
+                ))
+                .start_at(now);
+            let _ = timeline.set_rate(id.clone(), rate);
+            (timeline, widget::Id::from(id))
+        };
+        let value = |timeline: &Timeline, id| timeline.get(id, 0).unwrap().value;
+
+        let (mut slow, id) = setup(0.5);
+        slow.now(at(100));
+        assert_eq!(0.5, value(&slow, &id));
+        assert!(!slow.is_idle());
+        slow.now(at(201));
+        assert_eq!(1.0, value(&slow, &id));
+        assert!(slow.is_idle());
+
+        let (mut fast, id) = setup(2.0);
+        fast.now(at(25));
+        assert_eq!(0.5, value(&fast, &id));
+        fast.now(at(51));
+        assert_eq!(1.0, value(&fast, &id));
+        assert!(fast.is_idle());
+
+        // Changing the rate continues from the current value.
+        let (mut timeline, id) = setup(1.0);
+        timeline.now(at(50));
+        let _ = timeline.set_rate(id.clone(), 0.0);
+        timeline.now(at(80));
+        assert_eq!(0.5, value(&timeline, &id));
+        assert!(timeline.is_idle());
+        let _ = timeline.set_rate(id.clone(), -1.0);
+        assert!(!timeline.is_idle());
+        timeline.now(at(105));
+        assert_eq!(0.25, value(&timeline, &id));
+        timeline.now(at(200));
+        assert_eq!(0.0, value(&timeline, &id));
+        assert!(timeline.is_idle());
+    }
+
+    #[test]
+    fn completed() {
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let chain = |id: &id::Toggler, millis| {
+            chain!(
+                id.clone(),
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(Duration::from_millis(millis)).percent(1.0),
+            )
+        };
+        let mut timeline = Timeline::new();
+        let (done, replaced, looping) = (
+            id::Toggler::unique(),
+            id::Toggler::unique(),
+            id::Toggler::unique(),
+        );
+        timeline
+            .set_chain(chain(&done, 100))
+            .set_chain(chain(&replaced, 100))
+            .set_chain(chain(&looping, 100).loop_forever())
+            .start_at(now);
+
+        let mut completed = Vec::new();
+        for millis in (16..=400).step_by(16) {
+            if millis == 96 {
+                timeline
+                    .set_chain(chain(&replaced, 200))
+                    .start_at(at(millis));
+            }
+            timeline.now(at(millis));
+            completed.extend(timeline.completed().map(|id| (id.clone(), millis)));
+        }
+        assert_eq!(
+            vec![(widget::Id::from(done), 112), (replaced.into(), 304)],
+            completed
+        );
+    }
+
+    #[test]
+    fn extend() {
+        let now = Instant::now();
+        let timeline_with = |id: &id::Toggler, percent| {
+            let mut timeline = Timeline::new();
+            timeline
+                .set_chain(chain!(
+                    id.clone(),
+                    toggler(Duration::ZERO).percent(0.0),
+                    toggler(Duration::from_millis(100)).percent(percent),
+                ))
+                .start_at(now);
+            timeline
+        };
+        let (first, second) = (id::Toggler::unique(), id::Toggler::unique());
+        let mut timeline = timeline_with(&first, 1.0);
+        let _ = timeline.extend(timeline_with(&second, 0.5));
+
+        timeline.now(now + Duration::from_millis(50));
+        assert_eq!(0.5, timeline.get(&first.into(), 0).unwrap().value);
+        assert_eq!(0.25, timeline.get(&second.clone().into(), 0).unwrap().value);
+
+        // The later timeline wins on collisions.
+        let _ = timeline.extend(timeline_with(&second, 1.0));
+        assert_eq!(0.5, timeline.get(&second.into(), 0).unwrap().value);
+    }
+
+    #[test]
+    fn clear_all() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let chain = || {
+            chain!(
+                id::Toggler::unique(),
+                toggler(Duration::ZERO),
+                toggler(Duration::from_millis(100)),
+            )
+        };
+        timeline
+            .set_chain(chain().loop_forever())
+            .set_chain(chain())
+            .start_at(now);
+        timeline.now(now + Duration::from_millis(200));
+        let _ = timeline.set_chain(chain()).pause_all();
+        assert_eq!(2, timeline.pending_count());
+        assert_eq!(1, timeline.active_count());
+        assert!(!timeline.is_idle());
+
+        let _ = timeline.clear_all();
+        assert_eq!(0, timeline.pending_count());
+        assert_eq!(0, timeline.active_count());
+        assert_eq!(None, timeline.now_instant());
+        assert!(timeline.is_idle());
+    }
+
+    #[test]
+    fn nearest_keyframe() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let id = id::Toggler::unique();
+        timeline
+            .set_chain(chain!(
+                id,
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(Duration::from_millis(100)).percent(1.0),
+                toggler(Duration::from_millis(300)).percent(0.0),
+            ))
+            .start_at(now);
+        let id = widget::Id::from(id);
+
+        assert_eq!(Some(at(0)), timeline.nearest_keyframe(&id, at(0)));
+        assert_eq!(Some(at(0)), timeline.nearest_keyframe(&id, at(40)));
+        assert_eq!(Some(at(100)), timeline.nearest_keyframe(&id, at(60)));
+        assert_eq!(Some(at(100)), timeline.nearest_keyframe(&id, at(249)));
+        assert_eq!(Some(at(400)), timeline.nearest_keyframe(&id, at(251)));
+        assert_eq!(Some(at(400)), timeline.nearest_keyframe(&id, at(1000)));
+        // Ties snap to the earlier keyframe.
+        assert_eq!(Some(at(0)), timeline.nearest_keyframe(&id, at(50)));
+        assert_eq!(
+            None,
+            timeline.nearest_keyframe(&widget::Id::unique(), at(0))
+        );
+    }
+
+    #[test]
+    fn start_at_returning() {
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        let first = id::Toggler::unique();
+        let second = id::Toggler::unique();
+        let ends = timeline
+            .set_chain(toggler_chain(&first))
+            .set_chain(chain!(
+                second,
+                toggler(Duration::ZERO).percent(0.0),
+                toggler(Duration::from_millis(300)).percent(1.0),
+            ))
+            .start_at_returning(now);
+
+        let first = widget::Id::from(first);
+        let second = widget::Id::from(second);
+        assert_eq!(2, ends.len());
+        assert_eq!(ends[&first], timeline.tracks[&first].0.end);
+        assert_eq!(ends[&second], timeline.tracks[&second].0.end);
+        assert_eq!(now + Duration::from_millis(300), ends[&second]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn keyframes_round_trip() {
+        let (id, loaded) = (id::Toggler::unique(), id::Toggler::unique());
+        let chain: Chain = chain!(
+            id.clone(),
+            toggler(Duration::ZERO).percent(0.0),
+            toggler(Duration::from_millis(100))
+                .percent(1.0)
+                .ease(crate::Quadratic::In),
+            toggler(crate::Speed::per_millis(0.01))
+                .percent(0.5)
+                .ease(crate::Spring::new(100.0, 10.0, 1.0)),
+        )
+        .loop_forever()
+        .into();
+
+        let json = serde_json::to_string(&Keyframes::from(chain.clone())).unwrap();
+        let keyframes: Keyframes = serde_json::from_str(&json).unwrap();
+        assert_eq!(Repeat::Forever, keyframes.repeat);
+
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(chain)
+            .set_chain(keyframes.into_chain(loaded.clone()))
+            .start_at(now);
+        for millis in (0..250).step_by(10) {
+            timeline.now(now + Duration::from_millis(millis));
+            assert_eq!(
+                timeline.get(&id.clone().into(), 0).unwrap().value,
+                timeline.get(&loaded.clone().into(), 0).unwrap().value,
+            );
+        }
     }
 }
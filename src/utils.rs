@@ -6,6 +6,8 @@ use crate::reexports::iced_core::{
     layout::{Limits, Node},
     Point, Size,
 };
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
 
 /// Collect iterator into static array without panicking or collecting into a Vec.
 ///
@@ -53,3 +55,48 @@ pub fn next_to_each_other(
         vec![left_node, right_node],
     )
 }
+
+/// A [`HashMap`] using [`FnvHasher`].
+pub type FnvHashMap<K, V> = HashMap<K, V, BuildHasherDefault<FnvHasher>>;
+
+/// The 64 bit FNV-1a hash. Much cheaper than the default SipHash for small
+/// keys like widget Ids, but it doesn't resist collisions chosen by an
+/// attacker, so only use it for keys that don't come from untrusted input.
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fnv_1a() {
+        let hash = |bytes: &[u8]| {
+            let mut hasher = FnvHasher::default();
+            hasher.write(bytes);
+            hasher.finish()
+        };
+        assert_eq!(0xcbf2_9ce4_8422_2325, hash(b""));
+        assert_eq!(0xaf63_dc4c_8601_ec8c, hash(b"a"));
+        assert_eq!(0x8594_4171_f739_67e8, hash(b"foobar"));
+    }
+}
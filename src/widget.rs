@@ -1,9 +1,25 @@
 #![allow(clippy::too_many_arguments)]
+pub mod accordion;
+pub mod background;
 pub mod cards;
+pub mod checkbox;
+pub mod clip_reveal;
 pub mod cosmic_toggler;
+pub mod fade_text;
+pub mod magnetic;
+pub mod rotate;
+pub mod typewriter;
 
+pub use accordion::Accordion;
+pub use background::Background;
 pub use cards::Cards;
+pub use checkbox::Checkbox;
+pub use clip_reveal::{clip_reveal, ClipReveal};
 pub use cosmic_toggler::Toggler;
+pub use fade_text::FadeText;
+pub use magnetic::{magnetic, Magnetic};
+pub use rotate::Rotate;
+pub use typewriter::typewriter;
 
 /// A convenience type to optimize style-able widgets,
 /// to only do the "expensize" style calculations if needed.
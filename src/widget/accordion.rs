@@ -0,0 +1,296 @@
+//! A single section that expands and collapses its content.
+use crate::reexports::iced_core::{
+    event, layout, mouse, overlay, renderer,
+    widget::{Operation, Tree},
+    Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// How far an [`Accordion`] needs to be expanded before its content gets
+/// events. Below this the content is mostly hidden, and clicks on it would
+/// be surprising.
+const INTERACTIVE_PERCENT: f32 = 0.9;
+
+/// Content whose height grows from nothing to its full height as the
+/// accordion expands. Build it with [`crate::anim!`] and an
+/// [`crate::id::Accordion`], and animate it with
+/// [`crate::chain::Accordion::on`] and [`crate::chain::Accordion::off`].
+///
+/// The content is laid out at its full height first, and only the top part
+/// of it is shown, so it isn't squashed as the accordion expands. That full
+/// height is measured without a height limit, so the content should shrink
+/// to fit vertically.
+#[allow(missing_debug_implementations)]
+pub struct Accordion<'a, Message, Renderer> {
+    content: Element<'a, Message, cosmic::Theme, Renderer>,
+    percent: f32,
+}
+
+impl<'a, Message, Renderer> Accordion<'a, Message, Renderer> {
+    /// Creates a new, expanded, [`Accordion`] around the content.
+    pub fn new(content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>) -> Self {
+        Accordion {
+            content: content.into(),
+            percent: 1.0,
+        }
+    }
+
+    /// How far the accordion is expanded. `0.0` is collapsed, `1.0` is expanded.
+    /// This is indented to automated cosmic-time use, and shouldn't
+    /// need to be called manually.
+    #[must_use]
+    pub fn percent(mut self, percent: f32) -> Self {
+        self.percent = percent;
+        self
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.percent >= INTERACTIVE_PERCENT
+    }
+}
+
+/// Whether the content is taller than the accordion, either while it expands
+/// or when held to a parent's limits, so the rest of the content is hidden.
+fn is_clipped(layout: Layout<'_>) -> bool {
+    layout
+        .children()
+        .next()
+        .is_some_and(|content| content.bounds().height > layout.bounds().height)
+}
+
+/// The height shown of content that is `full` high, once the accordion is
+/// `percent` of the way expanded.
+#[must_use]
+pub fn expanded_height(full: f32, percent: f32) -> f32 {
+    full * percent.clamp(0.0, 1.0)
+}
+
+impl<'a, Message, Renderer> Widget<Message, cosmic::Theme, Renderer>
+    for Accordion<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [self.content.as_widget_mut()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.content.as_widget().size().width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let full_limits = layout::Limits::new(
+            Size::new(limits.min().width, 0.0),
+            Size::new(limits.max().width, f32::INFINITY),
+        );
+        let content =
+            self.content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, &full_limits);
+        let full = content.size();
+        let size = limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(full.width, expanded_height(full.height, self.percent)),
+        );
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.is_interactive() {
+            return event::Status::Ignored;
+        }
+        // The hidden part of the content shouldn't catch the cursor.
+        let cursor = if is_clipped(layout) && !cursor.is_over(layout.bounds()) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.is_interactive() || !cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::default();
+        }
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &cosmic::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if self.percent <= 0.0 {
+            return;
+        }
+        let draw = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        };
+        if is_clipped(layout) {
+            renderer.with_layer(layout.bounds(), draw);
+        } else {
+            draw(renderer);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, cosmic::Theme, Renderer>> {
+        if !self.is_interactive() {
+            return None;
+        }
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Accordion<'a, Message, Renderer>>
+    for Element<'a, Message, cosmic::Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(accordion: Accordion<'a, Message, Renderer>) -> Self {
+        Element::new(accordion)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Content of a fixed size, to measure.
+    struct Fixed(Size);
+
+    impl Widget<(), cosmic::Theme, ()> for Fixed {
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Fixed(self.0.width), Length::Fixed(self.0.height))
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(limits.resolve(self.0.width, self.0.height, Size::ZERO))
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &cosmic::Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    #[test]
+    fn half_expanded() {
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 60.0));
+        let layout = |percent| {
+            let accordion =
+                Accordion::new(Element::new(Fixed(Size::new(80.0, 100.0)))).percent(percent);
+            let mut tree = Tree::new(&accordion as &dyn Widget<_, _, _>);
+            accordion.layout(&mut tree, &(), &limits)
+        };
+
+        let half = layout(0.5);
+        assert_eq!(Size::new(80.0, 50.0), half.size());
+        // The content keeps its full height, even past the parent's limits.
+        assert_eq!(100.0, half.children()[0].size().height);
+        assert!(is_clipped(Layout::new(&half)));
+        assert_eq!(Size::new(80.0, 0.0), layout(0.0).size());
+        // Fully expanded is still held to the parent's limits, and clipped to them.
+        let full = layout(1.0);
+        assert_eq!(Size::new(80.0, 60.0), full.size());
+        assert!(is_clipped(Layout::new(&full)));
+
+        let accordion = Accordion::new(Element::new(Fixed(Size::new(80.0, 100.0))));
+        let mut tree = Tree::new(&accordion as &dyn Widget<_, _, _>);
+        let roomy = layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0));
+        let fits = accordion.layout(&mut tree, &(), &roomy);
+        assert!(!is_clipped(Layout::new(&fits)));
+    }
+}
@@ -0,0 +1,183 @@
+//! Content drawn over an animated background color.
+use crate::reexports::iced_core::{
+    event, layout, mouse, overlay, renderer,
+    widget::{Operation, Tree},
+    Border, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// Content drawn over a background color that is set directly, rather than
+/// through the theme. Build it with [`crate::anim!`] and an
+/// [`crate::id::Background`]. Layout and events pass straight through to
+/// the content.
+#[allow(missing_debug_implementations)]
+pub struct Background<'a, Message, Renderer> {
+    content: Element<'a, Message, cosmic::Theme, Renderer>,
+    color: Color,
+    radius: f32,
+}
+
+impl<'a, Message, Renderer> Background<'a, Message, Renderer> {
+    /// Creates a new [`Background`] wrapping the content, transparent.
+    pub fn new(content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>) -> Self {
+        Background {
+            content: content.into(),
+            color: Color::TRANSPARENT,
+            radius: 0.0,
+        }
+    }
+
+    /// Rounds the corners of the background.
+    #[must_use]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// The background color.
+    /// This is indented to automated cosmic-time use, and shouldn't
+    /// need to be called manually.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, cosmic::Theme, Renderer>
+    for Background<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [self.content.as_widget_mut()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &cosmic::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if self.color.a > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border: Border {
+                        radius: self.radius.into(),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                self.color,
+            );
+        }
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, cosmic::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Renderer> From<Background<'a, Message, Renderer>>
+    for Element<'a, Message, cosmic::Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(background: Background<'a, Message, Renderer>) -> Self {
+        Element::new(background)
+    }
+}
@@ -1,7 +1,13 @@
 //! An expandable stack of cards
 use self::iced_core::{
-    border::Radius, event::Status, id::Id, layout::Node, renderer::Quad, widget::Tree, Element,
-    Length, Size, Vector, Widget,
+    border::Radius,
+    event::Status,
+    id::Id,
+    layout::Node,
+    mouse,
+    renderer::Quad,
+    widget::{tree, Tree},
+    window, Color, Element, Length, Size, Vector, Widget,
 };
 use cosmic::{
     iced_core::{self, Border, Shadow},
@@ -9,7 +15,7 @@ use cosmic::{
 };
 use float_cmp::approx_eq;
 
-use crate::{chain, id};
+use crate::{chain, id, lerp, Duration, Instant, Quadratic, Tween};
 
 const ICON_SIZE: u16 = 16;
 const TOP_SPACING: u16 = 4;
@@ -18,6 +24,8 @@ const PADDING: u16 = 16;
 const BG_CARD_VISIBLE_HEIGHT: f32 = 4.0;
 const BG_CARD_BORDER_RADIUS: f32 = 8.0;
 const BG_CARD_MARGIN_STEP: f32 = 8.0;
+const HOVER_LIFT_DURATION: Duration = Duration::from_millis(100);
+const REORDER_DURATION: Duration = Duration::from_millis(200);
 
 /// get an expandable stack of cards
 #[allow(clippy::too_many_arguments)]
@@ -84,6 +92,177 @@ where
     width: Length,
     percent: f32,
     anim_multiplier: f32,
+    hover_lift: f32,
+    keys: Vec<u64>,
+    reduced_motion: bool,
+}
+
+/// The hover state of each card, used to animate the hover lift,
+/// and where each card was, used to animate reordering.
+#[derive(Debug, Default)]
+struct State {
+    now: Option<Instant>,
+    hovered: Option<usize>,
+    // How far each card is lifted, as a fraction of the hover lift.
+    lifts: Vec<Glide>,
+    laid_out: Option<(Vec<u64>, Vec<f32>)>,
+    order: Vec<u64>,
+    ys: Vec<f32>,
+    // How far each card is from its laid out position, after a reorder.
+    slides: Vec<Glide>,
+}
+
+impl State {
+    fn now(&self) -> Instant {
+        self.now.unwrap_or_else(Instant::now)
+    }
+
+    /// Starts the changes seen since the last frame, at the frame's instant.
+    /// With reduced motion, cards snap to where they are going instead.
+    /// Returns `true` if another frame is needed.
+    fn redraw(&mut self, len: usize, reduced_motion: bool, now: Instant) -> bool {
+        self.now = Some(now);
+        let _ = self.hover(self.hovered, len, now);
+        if let Some((keys, ys)) = self.laid_out.take() {
+            self.reorder(&keys, &ys, now);
+        }
+        if reduced_motion {
+            self.lifts
+                .iter_mut()
+                .chain(&mut self.slides)
+                .for_each(Glide::snap);
+        }
+        self.is_animating(now)
+    }
+
+    /// Whether the last layout changed the order of the cards, and the
+    /// slide is waiting for a frame to start.
+    fn is_reordered(&self) -> bool {
+        self.laid_out
+            .as_ref()
+            .is_some_and(|(keys, _ys)| *keys != self.order)
+    }
+
+    /// Updates which card is hovered. Returns `true` if anything changed.
+    fn hover(&mut self, hovered: Option<usize>, len: usize, now: Instant) -> bool {
+        self.lifts.resize(len, Glide::new(HOVER_LIFT_DURATION));
+        let mut changed = false;
+        for (i, lift) in self.lifts.iter_mut().enumerate() {
+            let to = if hovered == Some(i) { 1.0 } else { 0.0 };
+            changed |= lift.glide_to(to, now);
+        }
+        changed
+    }
+
+    /// Records where each card was laid out. If the order of the keys changed,
+    /// cards slide from where they were shown to their new position.
+    fn reorder(&mut self, keys: &[u64], ys: &[f32], now: Instant) {
+        if self.order != keys {
+            let shown: Vec<f32> = self
+                .ys
+                .iter()
+                .enumerate()
+                .map(|(i, y)| y + self.slides.get(i).map_or(0.0, |slide| slide.value(now)))
+                .collect();
+            self.slides = reorder_offsets(&self.order, &shown, keys, ys)
+                .into_iter()
+                .map(|from| Glide {
+                    from,
+                    changed_at: (from != 0.0).then_some(now),
+                    ..Glide::new(REORDER_DURATION)
+                })
+                .collect();
+            self.order = keys.to_vec();
+        }
+        self.ys = ys.to_vec();
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        self.lifts.iter().any(|lift| lift.is_animating(now))
+            || self.slides.iter().any(|slide| slide.is_animating(now))
+    }
+
+    fn slide(&self, index: usize) -> f32 {
+        self.slides
+            .get(index)
+            .map_or(0.0, |slide| slide.value(self.now()))
+    }
+
+    fn offset(&self, index: usize, max: f32) -> f32 {
+        self.lifts
+            .get(index)
+            .map_or(0.0, |lift| max * lift.value(self.now()))
+    }
+}
+
+/// A value easing out to `to` over `duration`, from wherever it was when it
+/// last changed. Used for both the hover lift and the reorder slide.
+#[derive(Debug, Clone, Copy)]
+struct Glide {
+    from: f32,
+    to: f32,
+    changed_at: Option<Instant>,
+    duration: Duration,
+}
+
+impl Glide {
+    fn new(duration: Duration) -> Self {
+        Glide {
+            from: 0.0,
+            to: 0.0,
+            changed_at: None,
+            duration,
+        }
+    }
+
+    fn value(&self, now: Instant) -> f32 {
+        let Some(changed_at) = self.changed_at else {
+            return self.to;
+        };
+        let percent = (now.saturating_duration_since(changed_at).as_secs_f32()
+            / self.duration.as_secs_f32())
+        .min(1.0);
+        lerp(self.from, self.to, Quadratic::Out.tween(percent))
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        self.changed_at
+            .is_some_and(|changed_at| now.saturating_duration_since(changed_at) < self.duration)
+    }
+
+    /// Starts easing to `to` from the current value. Returns `true` if `to` changed.
+    fn glide_to(&mut self, to: f32, now: Instant) -> bool {
+        if to == self.to {
+            return false;
+        }
+        *self = Glide {
+            from: self.value(now),
+            to,
+            changed_at: Some(now),
+            duration: self.duration,
+        };
+        true
+    }
+
+    /// Jumps to the end.
+    fn snap(&mut self) {
+        self.changed_at = None;
+    }
+}
+
+/// The offset each card starts sliding from after a reorder, so that it is
+/// first shown where its key used to be. New keys don't slide.
+fn reorder_offsets(old_keys: &[u64], old_ys: &[f32], keys: &[u64], ys: &[f32]) -> Vec<f32> {
+    keys.iter()
+        .zip(ys)
+        .map(|(key, y)| {
+            old_keys
+                .iter()
+                .position(|old| old == key)
+                .and_then(|i| old_ys.get(i))
+                .map_or(0.0, |old_y| old_y - y)
+        })
+        .collect()
 }
 
 impl<'a, Message> Cards<'a, Message, cosmic::Renderer>
@@ -180,6 +359,9 @@ where
             width: Length::Shrink,
             percent: if expanded { 1.0 } else { 0.0 },
             anim_multiplier: 1.0,
+            hover_lift: 0.0,
+            keys: Vec::new(),
+            reduced_motion: false,
             expanded,
         }
     }
@@ -208,6 +390,84 @@ where
         self.anim_multiplier = multiplier;
         self
     }
+
+    #[must_use]
+    /// Lift a hovered card up by this many pixels, with a shadow beneath it.
+    /// Disabled by default.
+    pub fn hover_lift(mut self, lift: f32) -> Self {
+        self.hover_lift = lift;
+        self
+    }
+
+    #[must_use]
+    /// A stable key for each card, in the same order as the cards.
+    /// When the order of the keys changes, cards slide from their old
+    /// positions to their new ones.
+    pub fn keys(mut self, keys: impl IntoIterator<Item = u64>) -> Self {
+        self.keys = keys.into_iter().collect();
+        self
+    }
+
+    #[must_use]
+    /// Snap the hover lift and the reorder slide to where they are going,
+    /// instead of animating them. Set from [`crate::Timeline::reduced_motion`]
+    /// when the cards are made with [`crate::anim!`].
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Cards<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced_core::Renderer + iced_core::text::Renderer,
+{
+    #[allow(clippy::too_many_arguments)]
+    fn draw_card(
+        &self,
+        index: usize,
+        state: &State,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &cosmic::Theme,
+        style: &iced_core::renderer::Style,
+        layout: iced_core::Layout<'_>,
+        cursor: iced_core::mouse::Cursor,
+        viewport: &iced_core::Rectangle,
+    ) {
+        let inner = &self.elements[index];
+        let lift = state.offset(index, self.hover_lift);
+        let slide = state.slide(index);
+        if lift > 0.0 {
+            renderer.fill_quad(
+                Quad {
+                    bounds: layout.bounds() + Vector::new(0.0, slide),
+                    border: Border {
+                        radius: Radius::from(BG_CARD_BORDER_RADIUS),
+                        ..Default::default()
+                    },
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.25),
+                        offset: Vector::new(0.0, lift),
+                        blur_radius: lift * 2.0,
+                    },
+                },
+                Color::TRANSPARENT,
+            );
+        }
+        if lift > 0.0 || slide != 0.0 {
+            renderer.with_translation(Vector::new(0.0, slide - lift), |renderer| {
+                inner
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+            });
+        } else {
+            inner
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, cosmic::Theme, Renderer>
@@ -216,6 +476,14 @@ where
     Message: 'a + Clone,
     Renderer: 'a + iced_core::Renderer + iced_core::text::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
         [&self.show_less_button, &self.clear_all_button]
             .iter()
@@ -311,6 +579,8 @@ where
             .max_width(size.width)
             .width(Length::Fixed(size.width));
 
+        let mut ys = Vec::with_capacity(self.elements.len());
+
         for (i, (c, t)) in self
             .elements
             .iter()
@@ -325,6 +595,7 @@ where
 
             size.height = size.height.max(progress + card_node.size().height);
 
+            ys.push(card_node.bounds().y);
             children.push(card_node);
 
             if fully_unexpanded {
@@ -353,6 +624,11 @@ where
             }
         }
 
+        // Layout can't ask for a redraw, so the slide starts at the next frame.
+        if !self.keys.is_empty() {
+            tree.state.downcast_mut::<State>().laid_out = Some((self.keys.clone(), ys));
+        }
+
         Node::with_children(size, children)
     }
 
@@ -384,6 +660,7 @@ where
         let fully_unexpanded = self.fully_unexpanded();
         let fully_expanded = self.fully_expanded();
 
+        let cards_state = state.state.downcast_ref::<State>();
         let mut layout = layout.children();
         let mut tree_children = state.children.iter();
 
@@ -445,7 +722,9 @@ where
                     },
                 );
             }
-            self.elements[0].as_widget().draw(
+            self.draw_card(
+                0,
+                cards_state,
                 tree_children.next().unwrap(),
                 renderer,
                 theme,
@@ -457,16 +736,22 @@ where
         } else {
             let layout = layout.collect::<Vec<_>>();
             // draw in reverse order so later cards appear behind earlier cards
-            for ((inner, layout), c_state) in self
-                .elements
-                .iter()
+            for ((i, layout), c_state) in (0..self.elements.len())
                 .rev()
                 .zip(layout.into_iter().rev())
                 .zip(tree_children.rev())
             {
-                inner
-                    .as_widget()
-                    .draw(c_state, renderer, theme, style, layout, cursor, viewport);
+                self.draw_card(
+                    i,
+                    cards_state,
+                    c_state,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    viewport,
+                );
             }
         }
     }
@@ -488,10 +773,47 @@ where
             return status;
         }
 
-        let mut layout = layout.children();
-        let mut tree_children = state.children.iter_mut();
         let fully_expanded = self.fully_expanded();
         let fully_unexpanded = self.fully_unexpanded();
+
+        if self.hover_lift > 0.0 || !self.keys.is_empty() {
+            let cards_state = state.state.downcast_mut::<State>();
+            match event {
+                iced_core::Event::Window(window::Event::RedrawRequested(now)) => {
+                    if cards_state.redraw(self.elements.len(), self.reduced_motion, now) {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+                iced_core::Event::Mouse(
+                    mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft,
+                ) if self.hover_lift > 0.0 => {
+                    let skip = if fully_expanded { 2 } else { 0 };
+                    let count = if fully_unexpanded {
+                        1
+                    } else {
+                        self.elements.len()
+                    };
+                    let hovered = layout
+                        .children()
+                        .skip(skip)
+                        .take(count)
+                        .position(|card| cursor.is_over(card.bounds()));
+                    // The lift starts on the next frame, so it is timed by the
+                    // same clock it is drawn with.
+                    if cards_state.hovered != hovered {
+                        cards_state.hovered = hovered;
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+                _ => {}
+            }
+            if cards_state.is_reordered() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        let mut layout = layout.children();
+        let mut tree_children = state.children.iter_mut();
         let show_less_state = tree_children.next();
         let clear_all_state = tree_children.next();
 
@@ -563,3 +885,116 @@ where
         Self::new(cards)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hover_lift_over_time() {
+        let start = Instant::now();
+        let mut state = State::default();
+        assert!(state.hover(Some(1), 3, start));
+
+        let at = |millis| start + Duration::from_millis(millis);
+        assert_eq!(0.0, state.lifts[1].value(at(0)));
+        assert_eq!(0.75, state.lifts[1].value(at(50)));
+        assert_eq!(1.0, state.lifts[1].value(at(100)));
+        assert_eq!(1.0, state.lifts[1].value(at(500)));
+        // Cards that are not hovered stay put.
+        assert_eq!(0.0, state.lifts[0].value(at(50)));
+        assert!(state.is_animating(at(50)));
+        assert!(!state.is_animating(at(100)));
+
+        // Leaving the card mid-animation lowers it from where it currently is.
+        assert!(state.hover(None, 3, at(50)));
+        assert_eq!(0.75, state.lifts[1].value(at(50)));
+        assert_eq!(0.0, state.lifts[1].value(at(150)));
+        assert!(!state.hover(None, 3, at(150)));
+    }
+
+    #[test]
+    fn hover_starts_at_redraw() {
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut state = State::default();
+        assert!(!state.redraw(3, false, at(0)));
+
+        // Hovered between frames, the lift is timed from the next frame.
+        state.hovered = Some(1);
+        assert!(state.redraw(3, false, at(30)));
+        assert_eq!(0.0, state.offset(1, 4.0));
+        assert!(state.redraw(3, false, at(80)));
+        assert_eq!(3.0, state.offset(1, 4.0));
+        assert!(!state.redraw(3, false, at(130)));
+        assert_eq!(4.0, state.offset(1, 4.0));
+    }
+
+    #[test]
+    fn reorder_after_swap() {
+        let ys = [0.0, 50.0, 100.0];
+        // The first two cards swap places, so each starts where the other was.
+        assert_eq!(
+            vec![50.0, -50.0, 0.0],
+            reorder_offsets(&[1, 2, 3], &ys, &[2, 1, 3], &ys)
+        );
+        // A new card doesn't slide, it has nowhere to come from.
+        assert_eq!(
+            vec![0.0, -50.0],
+            reorder_offsets(&[1, 2, 3], &ys, &[4, 1], &ys[..2])
+        );
+
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut state = State::default();
+        state.reorder(&[1, 2, 3], &ys, at(0));
+        assert!(!state.is_animating(at(0)));
+        state.reorder(&[2, 1, 3], &ys, at(0));
+        assert_eq!(50.0, state.slides[0].value(at(0)));
+        assert_eq!(12.5, state.slides[0].value(at(100)));
+        assert_eq!(0.0, state.slides[0].value(at(200)));
+        assert_eq!(-50.0, state.slides[1].value(at(0)));
+        assert!(state.is_animating(at(100)));
+        assert!(!state.is_animating(at(200)));
+    }
+
+    #[test]
+    fn reorder_starts_at_redraw() {
+        let ys = vec![0.0, 50.0];
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut state = State {
+            laid_out: Some((vec![1, 2], ys.clone())),
+            ..State::default()
+        };
+        assert!(!state.redraw(2, false, at(0)));
+
+        // Only layout sees the new order, and frames alone play the slide.
+        state.laid_out = Some((vec![2, 1], ys));
+        assert!(state.is_reordered());
+        assert!(state.redraw(2, false, at(30)));
+        assert!(!state.is_reordered());
+        assert_eq!(50.0, state.slide(0));
+        assert!(state.redraw(2, false, at(130)));
+        assert_eq!(12.5, state.slide(0));
+        assert!(!state.redraw(2, false, at(230)));
+        assert_eq!(0.0, state.slide(0));
+    }
+
+    #[test]
+    fn reduced_motion_snaps() {
+        let ys = vec![0.0, 50.0];
+        let start = Instant::now();
+        let mut state = State {
+            laid_out: Some((vec![1, 2], ys.clone())),
+            ..State::default()
+        };
+        assert!(!state.redraw(2, true, start));
+
+        state.hovered = Some(1);
+        state.laid_out = Some((vec![2, 1], ys));
+        assert!(!state.redraw(2, true, start));
+        assert_eq!(4.0, state.offset(1, 4.0));
+        assert_eq!(0.0, state.slide(0));
+    }
+}
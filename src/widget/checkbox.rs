@@ -0,0 +1,374 @@
+//! Show check controls using checkboxes.
+
+use cosmic::iced_widget::checkbox::Status;
+use iced_core::{
+    alignment, event, layout, mouse, renderer, text,
+    widget::{tree, Tree},
+    Clipboard, Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size, Widget,
+};
+
+use crate::{
+    chain, id,
+    reexports::{iced, iced_core, iced_widget},
+};
+pub use cosmic::iced_widget::checkbox::{Catalog, Style};
+
+/// A checkbox widget, whose checkmark is drawn in as it is checked.
+///
+/// Like the [`crate::widget::Toggler`], clicking it publishes the animation
+/// to play along with the new state, and it is up to the application to
+/// start it on the timeline.
+#[allow(missing_debug_implementations)]
+pub struct Checkbox<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    id: id::Checkbox,
+    is_checked: bool,
+    on_toggle: Box<dyn Fn(chain::Checkbox, bool) -> Message + 'a>,
+    label: Option<String>,
+    width: Length,
+    size: f32,
+    text_size: Option<f32>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    spacing: f32,
+    font: Option<Renderer::Font>,
+    percent: f32,
+    anim_multiplier: f32,
+}
+
+impl<'a, Message, Renderer> Checkbox<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default size of a [`Checkbox`].
+    pub const DEFAULT_SIZE: f32 = 16.0;
+
+    /// The default spacing of a [`Checkbox`].
+    pub const DEFAULT_SPACING: f32 = 8.0;
+
+    /// Creates a new [`Checkbox`].
+    ///
+    /// It expects:
+    ///   * a boolean describing whether the [`Checkbox`] is checked or not
+    ///   * An optional label for the [`Checkbox`]
+    ///   * a function that will be called when the [`Checkbox`] is toggled. It
+    ///     will receive the animation and the new state of the [`Checkbox`],
+    ///     and must produce a `Message`.
+    pub fn new<F>(
+        id: id::Checkbox,
+        label: impl Into<Option<String>>,
+        is_checked: bool,
+        f: F,
+    ) -> Self
+    where
+        F: 'a + Fn(chain::Checkbox, bool) -> Message,
+    {
+        Checkbox {
+            id,
+            is_checked,
+            on_toggle: Box::new(f),
+            label: label.into(),
+            width: Length::Shrink,
+            size: Self::DEFAULT_SIZE,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::Advanced,
+            spacing: Self::DEFAULT_SPACING,
+            font: None,
+            percent: if is_checked { 1.0 } else { 0.0 },
+            anim_multiplier: 1.0,
+        }
+    }
+
+    /// Sets the size of the box of the [`Checkbox`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Checkbox`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the [`Checkbox`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into().0);
+        self
+    }
+
+    /// Sets the text [`LineHeight`] of the [`Checkbox`].
+    ///
+    /// [`LineHeight`]: text::LineHeight
+    pub fn text_line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`Checkbox`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the spacing between the box of the [`Checkbox`] and the text.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Font`] of the text of the [`Checkbox`]
+    ///
+    /// [`Font`]: cosmic::iced::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// The percent completion of the checkmark animation.
+    /// This is indented to automated cosmic-time use, and shouldn't
+    /// need to be called manually.
+    pub fn percent(mut self, percent: f32) -> Self {
+        self.percent = percent;
+        self
+    }
+
+    /// The default animation time is 100ms, to speed up the check
+    /// animation use a value less than 1.0, and to slow down the
+    /// animation use a value greater than 1.0.
+    pub fn anim_multiplier(mut self, multiplier: f32) -> Self {
+        self.anim_multiplier = multiplier;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, cosmic::Theme, Renderer>
+    for Checkbox<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(iced_widget::text::State::<Renderer::Paragraph>::default())
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+
+        crate::utils::next_to_each_other(
+            &limits,
+            self.spacing,
+            |_| layout::Node::new(Size::new(self.size, self.size)),
+            |limits| {
+                if let Some(label) = self.label.as_deref() {
+                    let state = tree
+                        .state
+                        .downcast_mut::<iced_widget::text::State<Renderer::Paragraph>>();
+
+                    iced_core::widget::text::layout(
+                        state,
+                        renderer,
+                        &limits.shrink(Size::new(self.size + self.spacing, 0.0)),
+                        self.width,
+                        Length::Shrink,
+                        label,
+                        self.text_line_height,
+                        self.text_size.map(iced::Pixels),
+                        self.font,
+                        alignment::Horizontal::Left,
+                        alignment::Vertical::Top,
+                        self.text_shaping,
+                        cosmic::iced_core::text::Wrapping::default(),
+                    )
+                } else {
+                    layout::Node::new(iced_core::Size::ZERO)
+                }
+            },
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let mouse_over = cursor_position.is_over(layout.bounds());
+
+                if mouse_over {
+                    let id = self.id.clone();
+                    let animation = if self.is_checked {
+                        chain::Checkbox::off(id, self.anim_multiplier)
+                    } else {
+                        chain::Checkbox::on(id, self.anim_multiplier)
+                    };
+                    shell.publish((self.on_toggle)(animation, !self.is_checked));
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor_position.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &cosmic::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let is_mouse_over = cursor_position.is_over(layout.bounds());
+        let mut children = layout.children();
+
+        let box_layout = children.next().unwrap();
+        let bounds = box_layout.bounds();
+
+        let class = <cosmic::Theme as Catalog>::default();
+        let status = |is_checked| {
+            if is_mouse_over {
+                Status::Hovered { is_checked }
+            } else {
+                Status::Active { is_checked }
+            }
+        };
+        let unchecked = theme.style(&class, status(false));
+        let checked = theme.style(&class, status(true));
+        let percent = self.percent.clamp(0.0, 1.0);
+
+        // Cross fade the box from its unchecked to its checked appearance.
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: unchecked.border,
+                ..renderer::Quad::default()
+            },
+            unchecked.background,
+        );
+        if percent > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: iced_core::Border {
+                        color: checked.border.color.scale_alpha(percent),
+                        ..checked.border
+                    },
+                    ..renderer::Quad::default()
+                },
+                checked.background.scale_alpha(percent),
+            );
+        }
+
+        // The checkmark is wiped in from the left, and fades in with it.
+        if let Some(clip) = checkmark_clip(bounds, self.percent).intersection(viewport) {
+            renderer.fill_text(
+                text::Text {
+                    content: Renderer::CHECKMARK_ICON.to_string(),
+                    font: Renderer::ICON_FONT,
+                    size: Pixels(bounds.height * 0.7),
+                    line_height: text::LineHeight::default(),
+                    bounds: bounds.size(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::default(),
+                },
+                bounds.center(),
+                checked.icon_color.scale_alpha(percent),
+                clip,
+            );
+        }
+
+        if self.label.is_some() {
+            let label_layout = children.next().unwrap();
+            let state: &iced_widget::text::State<Renderer::Paragraph> = tree.state.downcast_ref();
+            iced_widget::text::draw(
+                renderer,
+                style,
+                label_layout,
+                state.0.raw(),
+                iced_widget::text::Style {
+                    color: unchecked.text_color,
+                },
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Checkbox<'a, Message, Renderer>>
+    for Element<'a, Message, cosmic::Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        checkbox: Checkbox<'a, Message, Renderer>,
+    ) -> Element<'a, Message, cosmic::Theme, Renderer> {
+        Element::new(checkbox)
+    }
+}
+
+/// The part of the box the checkmark is drawn in. Anchored to the left,
+/// where the checkmark's stroke starts, and grows with `percent`.
+fn checkmark_clip(bounds: Rectangle, percent: f32) -> Rectangle {
+    Rectangle {
+        width: bounds.width * percent.clamp(0.0, 1.0),
+        ..bounds
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn checkmark_clip_width() {
+        let bounds = Rectangle::new(iced_core::Point::new(10.0, 5.0), Size::new(16.0, 16.0));
+        assert_eq!(0.0, checkmark_clip(bounds, 0.0).width);
+        assert_eq!(8.0, checkmark_clip(bounds, 0.5).width);
+        assert_eq!(16.0, checkmark_clip(bounds, 1.0).width);
+        // Overshooting eases never draw outside the box.
+        assert_eq!(16.0, checkmark_clip(bounds, 1.2).width);
+        assert_eq!(0.0, checkmark_clip(bounds, -0.2).width);
+        assert_eq!(bounds.x, checkmark_clip(bounds, 0.5).x);
+    }
+}
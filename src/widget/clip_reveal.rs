@@ -0,0 +1,322 @@
+//! Content that is revealed by a growing clip, as a wipe or a circle.
+use crate::reexports::iced_core::{
+    event, layout, mouse, overlay, renderer,
+    widget::{Operation, Tree},
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use crate::{id, Edge, Timeline};
+
+/// Wraps content so that it is revealed as a two-state animation goes
+/// from "off" to "on". Animate it with [`crate::chain::TwoState::on`].
+/// Without an animation, all of the content is shown.
+pub fn clip_reveal<'a, Message, Renderer>(
+    id: &id::TwoState,
+    timeline: &Timeline,
+    content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>,
+) -> ClipReveal<'a, Message, Renderer> {
+    ClipReveal::new(content).percent(timeline.two_state(&id.clone().into()).unwrap_or(1.0))
+}
+
+/// The shape of the clip that reveals the content.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reveal {
+    /// Wipe the content in, starting from this edge.
+    Wipe(Edge),
+    /// Expand from this point, relative to the content's top left corner.
+    /// Renderers can only clip to rectangles, so the circle is revealed
+    /// by its bounding square.
+    Circle(Point),
+}
+
+/// Content that is revealed by a growing clip.
+/// Only the drawing is clipped, layout and events use the full bounds.
+#[allow(missing_debug_implementations)]
+pub struct ClipReveal<'a, Message, Renderer> {
+    content: Element<'a, Message, cosmic::Theme, Renderer>,
+    reveal: Reveal,
+    percent: f32,
+}
+
+impl<'a, Message, Renderer> ClipReveal<'a, Message, Renderer> {
+    /// Creates a new [`ClipReveal`] wrapping the content, fully shown and
+    /// wiping in from the left.
+    pub fn new(content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>) -> Self {
+        ClipReveal {
+            content: content.into(),
+            reveal: Reveal::Wipe(Edge::Left),
+            percent: 1.0,
+        }
+    }
+
+    /// Expand the clip as a circle from `point`, relative to the content's
+    /// top left corner.
+    #[must_use]
+    pub fn circle_from(mut self, point: Point) -> Self {
+        self.reveal = Reveal::Circle(point);
+        self
+    }
+
+    /// Wipe the content in, starting from `edge`.
+    #[must_use]
+    pub fn wipe(mut self, edge: Edge) -> Self {
+        self.reveal = Reveal::Wipe(edge);
+        self
+    }
+
+    /// How much of the content is revealed. `0.0` is hidden, `1.0` is fully shown.
+    /// This is indented to automated cosmic-time use, and shouldn't
+    /// need to be called manually.
+    #[must_use]
+    pub fn percent(mut self, percent: f32) -> Self {
+        self.percent = percent;
+        self
+    }
+}
+
+/// The radius of the circle that reveals `bounds` from `center`, once
+/// `percent` of the way. At `1.0` it reaches the furthest corner.
+#[must_use]
+pub fn radius(bounds: Size, center: Point, percent: f32) -> f32 {
+    let dx = center.x.max(bounds.width - center.x);
+    let dy = center.y.max(bounds.height - center.y);
+    dx.hypot(dy) * percent.clamp(0.0, 1.0)
+}
+
+/// The part of `bounds` that is drawn, once `percent` of the way through the reveal.
+#[must_use]
+pub fn clip(bounds: Rectangle, reveal: Reveal, percent: f32) -> Rectangle {
+    let percent = percent.clamp(0.0, 1.0);
+    match reveal {
+        Reveal::Wipe(Edge::Left) => Rectangle {
+            width: bounds.width * percent,
+            ..bounds
+        },
+        Reveal::Wipe(Edge::Right) => Rectangle {
+            x: bounds.x + bounds.width * (1.0 - percent),
+            width: bounds.width * percent,
+            ..bounds
+        },
+        Reveal::Wipe(Edge::Top) => Rectangle {
+            height: bounds.height * percent,
+            ..bounds
+        },
+        Reveal::Wipe(Edge::Bottom) => Rectangle {
+            y: bounds.y + bounds.height * (1.0 - percent),
+            height: bounds.height * percent,
+            ..bounds
+        },
+        Reveal::Circle(center) => {
+            let radius = radius(bounds.size(), center, percent);
+            let square = Rectangle::new(
+                bounds.position() + Vector::new(center.x - radius, center.y - radius),
+                Size::new(radius * 2.0, radius * 2.0),
+            );
+            square.intersection(&bounds).unwrap_or(Rectangle {
+                width: 0.0,
+                height: 0.0,
+                ..bounds
+            })
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, cosmic::Theme, Renderer>
+    for ClipReveal<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [self.content.as_widget_mut()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &cosmic::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if self.percent <= 0.0 {
+            return;
+        }
+        let draw = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        };
+        if self.percent >= 1.0 {
+            draw(renderer);
+        } else {
+            renderer.with_layer(clip(layout.bounds(), self.reveal, self.percent), draw);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, cosmic::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Renderer> From<ClipReveal<'a, Message, Renderer>>
+    for Element<'a, Message, cosmic::Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(clip_reveal: ClipReveal<'a, Message, Renderer>) -> Self {
+        Element::new(clip_reveal)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{chain, Duration, Instant, Linear};
+
+    #[test]
+    fn wipes() {
+        let bounds = Rectangle::new(Point::new(10.0, 20.0), Size::new(100.0, 40.0));
+        let left = clip(bounds, Reveal::Wipe(Edge::Left), 0.25);
+        assert_eq!(
+            Rectangle::new(Point::new(10.0, 20.0), Size::new(25.0, 40.0)),
+            left
+        );
+        let right = clip(bounds, Reveal::Wipe(Edge::Right), 0.25);
+        assert_eq!(
+            Rectangle::new(Point::new(85.0, 20.0), Size::new(25.0, 40.0)),
+            right
+        );
+        let bottom = clip(bounds, Reveal::Wipe(Edge::Bottom), 0.5);
+        assert_eq!(
+            Rectangle::new(Point::new(10.0, 40.0), Size::new(100.0, 20.0)),
+            bottom
+        );
+        assert_eq!(bounds, clip(bounds, Reveal::Wipe(Edge::Top), 1.0));
+    }
+
+    #[test]
+    fn circle_over_time() {
+        let id = id::TwoState::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(chain::TwoState::on(
+                id.clone(),
+                Duration::from_millis(100),
+                Linear::InOut,
+            ))
+            .start_at(now);
+
+        let bounds = Rectangle::new(Point::new(10.0, 20.0), Size::new(60.0, 80.0));
+        // From the top left corner, the furthest corner is 100 pixels away.
+        let reveal = Reveal::Circle(Point::ORIGIN);
+        let mut clip_at = |millis| {
+            timeline.now(now + Duration::from_millis(millis));
+            let percent = timeline.two_state(&id.clone().into()).unwrap();
+            (
+                radius(bounds.size(), Point::ORIGIN, percent),
+                clip(bounds, reveal, percent),
+            )
+        };
+        let (radius, hidden) = clip_at(0);
+        assert_eq!(0.0, radius);
+        assert_eq!(Size::ZERO, hidden.size());
+        let (radius, quarter) = clip_at(25);
+        assert_eq!(25.0, radius);
+        assert_eq!(
+            Rectangle::new(Point::new(10.0, 20.0), Size::new(25.0, 25.0)),
+            quarter
+        );
+        let (radius, shown) = clip_at(100);
+        assert_eq!(100.0, radius);
+        assert_eq!(bounds, shown);
+    }
+}
@@ -10,6 +10,7 @@ use iced_core::{
 use crate::{
     chain, id, lerp,
     reexports::{iced, iced_core, iced_widget},
+    Duration, Ease,
 };
 pub use cosmic::iced_widget::toggler::{Catalog, Style};
 
@@ -33,6 +34,9 @@ where
     font: Option<Renderer::Font>,
     percent: f32,
     anim_multiplier: f32,
+    on_transition: Option<(Duration, Ease)>,
+    off_transition: Option<(Duration, Ease)>,
+    sweep_fill: bool,
 }
 
 impl<'a, Message, Renderer> Toggler<'a, Message, Renderer>
@@ -69,6 +73,9 @@ where
             font: None,
             percent: if is_toggled { 1.0 } else { 0.0 },
             anim_multiplier: 1.0,
+            on_transition: None,
+            off_transition: None,
+            sweep_fill: false,
         }
     }
 
@@ -129,6 +136,27 @@ where
         self.percent = percent;
         self
     }
+
+    /// The duration and ease used when toggling on. By default, both
+    /// directions use the same linear animation.
+    pub fn on_transition(mut self, duration: Duration, ease: impl Into<Ease>) -> Self {
+        self.on_transition = Some((duration, ease.into()));
+        self
+    }
+
+    /// The duration and ease used when toggling off. By default, both
+    /// directions use the same linear animation.
+    pub fn off_transition(mut self, duration: Duration, ease: impl Into<Ease>) -> Self {
+        self.off_transition = Some((duration, ease.into()));
+        self
+    }
+
+    /// Rather than blending the whole track between the off and on colors,
+    /// sweep the on color across the track from the handle's side as it toggles.
+    pub fn sweep_fill(mut self, sweep_fill: bool) -> Self {
+        self.sweep_fill = sweep_fill;
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, cosmic::Theme, Renderer>
@@ -210,13 +238,18 @@ where
                 let mouse_over = cursor_position.is_over(layout.bounds());
 
                 if mouse_over {
+                    let id = self.id.clone();
                     if self.is_toggled {
-                        let off_animation =
-                            chain::Toggler::off(self.id.clone(), self.anim_multiplier);
+                        let off_animation = match self.off_transition {
+                            Some((duration, ease)) => chain::Toggler::off_with(id, duration, ease),
+                            None => chain::Toggler::off(id, self.anim_multiplier),
+                        };
                         shell.publish((self.on_toggle)(off_animation, !self.is_toggled));
                     } else {
-                        let on_animation =
-                            chain::Toggler::on(self.id.clone(), self.anim_multiplier);
+                        let on_animation = match self.on_transition {
+                            Some((duration, ease)) => chain::Toggler::on_with(id, duration, ease),
+                            None => chain::Toggler::on(id, self.anim_multiplier),
+                        };
                         shell.publish((self.on_toggle)(on_animation, !self.is_toggled));
                     }
 
@@ -274,23 +307,17 @@ where
 
         let is_mouse_over = cursor_position.is_over(bounds);
 
+        let status = |is_toggled| {
+            if is_mouse_over {
+                Status::Hovered { is_toggled }
+            } else {
+                Status::Active { is_toggled }
+            }
+        };
+
         let style = blend_appearances(
-            theme.style(
-                &(),
-                if is_mouse_over {
-                    Status::Hovered { is_toggled: false }
-                } else {
-                    Status::Active { is_toggled: false }
-                },
-            ),
-            theme.style(
-                &(),
-                if is_mouse_over {
-                    Status::Hovered { is_toggled: true }
-                } else {
-                    Status::Active { is_toggled: true }
-                },
-            ),
+            theme.style(&(), status(false)),
+            theme.style(&(), status(true)),
             self.percent,
         );
 
@@ -312,9 +339,27 @@ where
                 },
                 ..renderer::Quad::default()
             },
-            style.background,
+            if self.sweep_fill {
+                theme.style(&(), status(false)).background
+            } else {
+                style.background
+            },
         );
 
+        if self.sweep_fill {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: sweep_fill_bounds(toggler_background_bounds, self.percent),
+                    border: Border {
+                        radius: style.border_radius,
+                        ..Default::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                theme.style(&(), status(true)).background,
+            );
+        }
+
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
                 + lerp(
@@ -355,6 +400,15 @@ where
     }
 }
 
+/// The region of the track covered by the on color in sweep fill mode.
+/// Anchored to the side the handle starts on, and grows with `percent`.
+fn sweep_fill_bounds(bounds: Rectangle, percent: f32) -> Rectangle {
+    Rectangle {
+        width: bounds.width * percent.clamp(0.0, 1.0),
+        ..bounds
+    }
+}
+
 fn blend_appearances(first: Style, mut other: Style, percent: f32) -> Style {
     if percent == 0. {
         first
@@ -376,3 +430,26 @@ fn blend_appearances(first: Style, mut other: Style, percent: f32) -> Style {
         other
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sweep_fill_width() {
+        let bounds = Rectangle::new(iced_core::Point::new(10.0, 5.0), Size::new(48.0, 24.0));
+        assert_eq!(0.0, sweep_fill_bounds(bounds, 0.0).width);
+        assert_eq!(12.0, sweep_fill_bounds(bounds, 0.25).width);
+        assert_eq!(24.0, sweep_fill_bounds(bounds, 0.5).width);
+        assert_eq!(48.0, sweep_fill_bounds(bounds, 1.0).width);
+        // Overshooting eases never paint outside the track.
+        assert_eq!(48.0, sweep_fill_bounds(bounds, 1.2).width);
+        assert_eq!(0.0, sweep_fill_bounds(bounds, -0.2).width);
+
+        let fill = sweep_fill_bounds(bounds, 0.5);
+        assert_eq!(
+            (bounds.x, bounds.y, bounds.height),
+            (fill.x, fill.y, fill.height)
+        );
+    }
+}
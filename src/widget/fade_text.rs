@@ -0,0 +1,197 @@
+//! Text that fades in and out.
+use crate::reexports::iced_core::{
+    event, layout, mouse, overlay, renderer,
+    widget::{Operation, Tree},
+    Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// Content whose text fades with an animated opacity. Build it with
+/// [`crate::anim!`] and an [`crate::id::Opacity`].
+///
+/// Only the text color the content inherits is faded, as iced renderers
+/// can't composite a layer with an alpha. Backgrounds, borders, icons and
+/// text with its own color stay opaque until the content is fully
+/// transparent, when it isn't drawn at all. To fade those, read
+/// [`crate::id::Opacity::opacity`] and apply it to their colors.
+/// Layout and events always pass straight through to the content.
+#[allow(missing_debug_implementations)]
+pub struct FadeText<'a, Message, Renderer> {
+    content: Element<'a, Message, cosmic::Theme, Renderer>,
+    opacity: f32,
+}
+
+impl<'a, Message, Renderer> FadeText<'a, Message, Renderer> {
+    /// Creates a new [`FadeText`] wrapping the content, fully opaque.
+    pub fn new(content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>) -> Self {
+        FadeText {
+            content: content.into(),
+            opacity: 1.0,
+        }
+    }
+
+    /// How opaque the content's text is. `0.0` is transparent, `1.0` is opaque.
+    /// This is indented to automated cosmic-time use, and shouldn't
+    /// need to be called manually.
+    #[must_use]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+}
+
+/// The renderer style for content drawn with `opacity`.
+#[must_use]
+pub fn faded(style: &renderer::Style, opacity: f32) -> renderer::Style {
+    let mut text_color = style.text_color;
+    text_color.a *= opacity.clamp(0.0, 1.0);
+    renderer::Style { text_color }
+}
+
+impl<'a, Message, Renderer> Widget<Message, cosmic::Theme, Renderer>
+    for FadeText<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [self.content.as_widget_mut()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &cosmic::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if self.opacity <= 0.0 {
+            return;
+        }
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &faded(style, self.opacity),
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, cosmic::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Renderer> From<FadeText<'a, Message, Renderer>>
+    for Element<'a, Message, cosmic::Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(fade_text: FadeText<'a, Message, Renderer>) -> Self {
+        Element::new(fade_text)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reexports::iced_core::Color;
+
+    #[test]
+    fn fades_text_color() {
+        let style = renderer::Style {
+            text_color: Color::from_rgba(0.2, 0.4, 0.6, 0.8),
+        };
+        assert_eq!(
+            Color::from_rgba(0.2, 0.4, 0.6, 0.4),
+            faded(&style, 0.5).text_color
+        );
+        assert_eq!(style.text_color, faded(&style, 1.0).text_color);
+        // Overshooting eases don't make the content more opaque than it is.
+        assert_eq!(style.text_color, faded(&style, 1.2).text_color);
+        assert_eq!(0.0, faded(&style, -0.2).text_color.a);
+    }
+}
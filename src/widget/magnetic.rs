@@ -0,0 +1,348 @@
+//! Content that is pulled toward the cursor while hovered.
+use crate::reexports::iced_core::{
+    event, layout, mouse, overlay, renderer,
+    widget::{tree, Operation, Tree},
+    window, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use crate::Instant;
+
+/// The angular frequency of the spring, per second. Settles in roughly 250ms.
+const SPRING_STIFFNESS: f32 = 25.0;
+/// Distance, in pixels, under which the spring is considered settled.
+const SETTLED: f32 = 0.01;
+
+/// Wraps content so that it is pulled toward the cursor while hovered,
+/// and springs back when the cursor leaves.
+pub fn magnetic<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>,
+) -> Magnetic<'a, Message, Renderer> {
+    Magnetic::new(content)
+}
+
+/// Content that is pulled toward the cursor while hovered.
+/// Only the drawing is offset, layout and events use the original bounds.
+#[allow(missing_debug_implementations)]
+pub struct Magnetic<'a, Message, Renderer> {
+    content: Element<'a, Message, cosmic::Theme, Renderer>,
+    strength: f32,
+    max_offset: f32,
+}
+
+impl<'a, Message, Renderer> Magnetic<'a, Message, Renderer> {
+    /// Creates a new [`Magnetic`] wrapping the content.
+    pub fn new(content: impl Into<Element<'a, Message, cosmic::Theme, Renderer>>) -> Self {
+        Magnetic {
+            content: content.into(),
+            strength: 0.25,
+            max_offset: 8.0,
+        }
+    }
+
+    /// How strongly the content is pulled toward the cursor, as a fraction
+    /// of the cursor's distance from the content's center.
+    #[must_use]
+    pub fn strength(mut self, strength: f32) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    /// The furthest, in pixels, the content can be pulled.
+    #[must_use]
+    pub fn max_offset(mut self, max_offset: f32) -> Self {
+        self.max_offset = max_offset;
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    now: Option<Instant>,
+    from: Vector,
+    target: Vector,
+    changed_at: Option<Instant>,
+    // The target seen since the last frame, started at the next frame's instant.
+    pending: Option<Vector>,
+}
+
+impl State {
+    /// Starts the target seen since the last frame, at the frame's instant.
+    /// Returns `true` if another frame is needed.
+    fn redraw(&mut self, now: Instant) -> bool {
+        self.now = Some(now);
+        if let Some(target) = self.pending.take() {
+            let _ = self.retarget(target, now);
+        }
+        self.is_animating(now)
+    }
+
+    fn offset(&self, now: Instant) -> Vector {
+        let Some(changed_at) = self.changed_at else {
+            return self.target;
+        };
+        let remaining = spring(now.saturating_duration_since(changed_at).as_secs_f32());
+        self.target + (self.from - self.target) * remaining
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        let distance = self.offset(now) - self.target;
+        distance.x.abs() > SETTLED || distance.y.abs() > SETTLED
+    }
+
+    /// Springs toward a new target from wherever the content currently is.
+    /// Returns `true` if the target changed.
+    fn retarget(&mut self, target: Vector, now: Instant) -> bool {
+        if target == self.target {
+            return false;
+        }
+        self.from = self.offset(now);
+        self.target = target;
+        self.changed_at = Some(now);
+        true
+    }
+}
+
+/// The fraction of the distance left to travel, `t` seconds after a
+/// critically damped spring at rest was released.
+fn spring(t: f32) -> f32 {
+    let wt = SPRING_STIFFNESS * t;
+    (1.0 + wt) * (-wt).exp()
+}
+
+/// Where content with `bounds` is pulled to, for a cursor at `cursor`.
+fn magnetic_target(bounds: Rectangle, cursor: Point, strength: f32, max_offset: f32) -> Vector {
+    let pull = (cursor - bounds.center()) * strength;
+    let length = pull.x.hypot(pull.y);
+    if length > max_offset {
+        pull * (max_offset / length)
+    } else {
+        pull
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, cosmic::Theme, Renderer>
+    for Magnetic<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut [self.content.as_widget_mut()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        match &event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if state.redraw(*now) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                let bounds = layout.bounds();
+                let target = cursor
+                    .position_over(bounds)
+                    .map_or(Vector::ZERO, |position| {
+                        magnetic_target(bounds, position, self.strength, self.max_offset)
+                    });
+                if target != state.pending.unwrap_or(state.target) {
+                    state.pending = Some(target);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &cosmic::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let offset = state.offset(state.now.unwrap_or_else(Instant::now));
+        renderer.with_translation(offset, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, cosmic::Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Renderer> From<Magnetic<'a, Message, Renderer>>
+    for Element<'a, Message, cosmic::Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(magnetic: Magnetic<'a, Message, Renderer>) -> Self {
+        Element::new(magnetic)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Duration;
+
+    #[test]
+    fn target_from_cursor() {
+        let bounds = Rectangle::new(Point::new(0.0, 0.0), Size::new(100.0, 40.0));
+        // Cursor on the center doesn't pull at all.
+        assert_eq!(
+            Vector::ZERO,
+            magnetic_target(bounds, Point::new(50.0, 20.0), 0.25, 8.0)
+        );
+        assert_eq!(
+            Vector::new(5.0, -2.5),
+            magnetic_target(bounds, Point::new(70.0, 10.0), 0.25, 8.0)
+        );
+        // Far away pulls are clamped to the max offset, keeping their direction.
+        assert_eq!(
+            Vector::new(8.0, 0.0),
+            magnetic_target(bounds, Point::new(100.0, 20.0), 0.25, 8.0)
+        );
+    }
+
+    #[test]
+    fn spring_return() {
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let mut state = State::default();
+        assert!(state.retarget(Vector::new(8.0, 0.0), now));
+        assert!(!state.retarget(Vector::new(8.0, 0.0), now));
+        assert_eq!(Vector::ZERO, state.offset(now));
+
+        let mut previous = 0.0;
+        for millis in [20, 40, 80, 160] {
+            let x = state.offset(at(millis)).x;
+            assert!(x > previous && x < 8.0);
+            previous = x;
+        }
+        assert!(state.is_animating(at(160)));
+        assert!(!state.is_animating(at(1000)));
+
+        // Leaving springs back to rest from wherever the content is.
+        let from = state.offset(at(1000));
+        assert!(state.retarget(Vector::ZERO, at(1000)));
+        assert_eq!(from, state.offset(at(1000)));
+        assert!(state.offset(at(1100)).x < from.x);
+        assert!(state.offset(at(2000)).x.abs() < SETTLED);
+    }
+
+    #[test]
+    fn retarget_starts_at_redraw() {
+        let now = Instant::now();
+        let at = |millis| now + Duration::from_millis(millis);
+        let mut state = State {
+            pending: Some(Vector::new(8.0, 0.0)),
+            ..State::default()
+        };
+        // The spring starts at the frame, however late after the cursor moved.
+        assert!(state.redraw(at(100)));
+        assert_eq!(None, state.pending);
+        assert_eq!(Some(at(100)), state.changed_at);
+        assert_eq!(Vector::ZERO, state.offset(at(100)));
+        assert!(!state.redraw(at(1000)));
+    }
+}
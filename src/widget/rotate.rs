@@ -0,0 +1,102 @@
+//! Vector graphics drawn at an animated angle.
+use crate::reexports::iced_core::{
+    layout, mouse, renderer, svg, widget::Tree, Element, Layout, Length, Radians, Rectangle, Size,
+    Widget,
+};
+
+/// An svg drawn rotated about its center. Build it with [`crate::anim!`]
+/// and an [`crate::id::Rotate`].
+///
+/// Renderers can only rotate images and vector graphics, not arbitrary
+/// widgets, so this draws an svg, like an icon. The layout, and so hit
+/// testing, always uses the unrotated bounds, so the drawing doesn't shift
+/// the widgets around it while spinning.
+#[derive(Debug)]
+pub struct Rotate {
+    handle: svg::Handle,
+    width: Length,
+    height: Length,
+    degrees: f32,
+}
+
+impl Rotate {
+    /// Creates a new, unrotated, [`Rotate`] drawing the svg.
+    #[must_use]
+    pub fn new(handle: svg::Handle) -> Self {
+        Rotate {
+            handle,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            degrees: 0.0,
+        }
+    }
+
+    /// Sets the width of the [`Rotate`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Rotate`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// The angle to draw at, in degrees clockwise.
+    /// This is indented to automated cosmic-time use, and shouldn't
+    /// need to be called manually.
+    #[must_use]
+    pub fn degrees(mut self, degrees: f32) -> Self {
+        self.degrees = degrees;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, cosmic::Theme, Renderer> for Rotate
+where
+    Renderer: svg::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Size { width, height } = renderer.measure_svg(&self.handle);
+        #[allow(clippy::cast_precision_loss)]
+        let intrinsic = Size::new(width as f32, height as f32);
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &cosmic::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        renderer.draw_svg(
+            svg::Svg::new(self.handle.clone()).rotation(Radians(self.degrees.to_radians())),
+            layout.bounds(),
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Rotate> for Element<'a, Message, cosmic::Theme, Renderer>
+where
+    Renderer: 'a + svg::Renderer,
+{
+    fn from(rotate: Rotate) -> Self {
+        Element::new(rotate)
+    }
+}
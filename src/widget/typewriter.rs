@@ -0,0 +1,65 @@
+//! Text that is revealed a character at a time, like it is being typed.
+use crate::{id, Timeline};
+
+/// Text that only shows as many characters as a frame index animation has
+/// counted up to. Animate it with [`crate::chain::FrameIndex::reveal`], with
+/// a count of `text.chars().count()`, as `text.len()` counts bytes.
+/// Without an animation, all of the text is shown.
+pub fn typewriter<'a, Message: 'a>(
+    id: &id::FrameIndex,
+    timeline: &Timeline,
+    text: &'a str,
+) -> cosmic::Element<'a, Message> {
+    let shown = timeline
+        .get_index(&id.clone().into())
+        .map_or(text, |count| typed(text, count));
+    cosmic::widget::text(shown).into()
+}
+
+/// The first `count` characters of `text`.
+#[must_use]
+pub fn typed(text: &str, count: usize) -> &str {
+    text.char_indices()
+        .nth(count)
+        .map_or(text, |(index, _)| &text[..index])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{chain, Duration, Instant};
+
+    #[test]
+    fn typed_chars() {
+        assert_eq!("", typed("héllo", 0));
+        assert_eq!("hé", typed("héllo", 2));
+        assert_eq!("héllo", typed("héllo", 5));
+        assert_eq!("héllo", typed("héllo", 50));
+    }
+
+    #[test]
+    fn reveal_schedule() {
+        let text = "typéwriter";
+        let id = id::FrameIndex::unique();
+        let mut timeline = Timeline::new();
+        let now = Instant::now();
+        timeline
+            .set_chain(chain::FrameIndex::reveal(
+                id.clone(),
+                text.chars().count(),
+                20.0,
+            ))
+            .start_at(now);
+
+        let mut shown_at = |millis| {
+            timeline.now(now + Duration::from_millis(millis));
+            typed(text, timeline.get_index(&id.clone().into()).unwrap())
+        };
+        assert_eq!("", shown_at(0));
+        assert_eq!("", shown_at(49));
+        assert_eq!("t", shown_at(50));
+        assert_eq!("typéw", shown_at(275));
+        assert_eq!("typéwriter", shown_at(500));
+        assert_eq!("typéwriter", shown_at(1000));
+    }
+}
@@ -0,0 +1,48 @@
+//! Reading animations happens in `view()` on every frame, so it must not allocate.
+mod common;
+
+use common::allocations;
+use cosmic_time::{chain, id, offset, toggler, Duration, Instant, Timeline};
+
+#[test]
+fn get_does_not_allocate() {
+    let id = id::Toggler::unique();
+    let mut timeline = Timeline::new();
+    let now = Instant::now();
+    timeline
+        .set_chain(chain!(
+            id,
+            toggler(Duration::ZERO).percent(0.0),
+            toggler(Duration::from_millis(100)).percent(1.0),
+            toggler(Duration::from_millis(100)).percent(0.0),
+        ))
+        .start_at(now);
+    timeline.now(now + Duration::from_millis(150));
+    let id = id.into();
+
+    assert_eq!(0, allocations(|| timeline.get(&id, 0)));
+    assert_eq!(0, allocations(|| timeline.try_get(&id, 1)));
+    assert_eq!(0, allocations(|| timeline.is_idle()));
+}
+
+#[test]
+fn start_short_chain_does_not_allocate() {
+    let id = id::Offset::unique();
+    let mut timeline = Timeline::new();
+    let now = Instant::now();
+    let chain = |keyframes: u16| {
+        (1..keyframes).fold(chain!(id, offset(Duration::ZERO)), |chain, i| {
+            chain.link(offset(Duration::from_millis(100)).x(f32::from(i)))
+        })
+    };
+    // Warm up, so the timeline's maps already have room for the track.
+    timeline.set_chain(chain(4)).start_at(now);
+
+    // Up to four keyframes of each value are kept inline.
+    for keyframes in 1..=4 {
+        let _ = timeline.set_chain(chain(keyframes));
+        assert_eq!(0, allocations(|| timeline.start_at(now)), "{keyframes}");
+    }
+    let _ = timeline.set_chain(chain(5));
+    assert_ne!(0, allocations(|| timeline.start_at(now)));
+}